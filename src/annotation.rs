@@ -0,0 +1,716 @@
+use crate::page::Page;
+use crate::{Color, Date, Point, Real, Rect, Result};
+
+use libharu_sys::{
+    HPDF_AnnotHighlightMode, HPDF_AnnotIcon, HPDF_BSSubtype, HPDF_LineAnnotEndingStyle, HPDF_Point,
+    HPDF_RGBColor,
+};
+use std::ffi::CString;
+
+use self::sealed::Sealed;
+
+/// Appearance of a link annotation when it is clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightMode {
+    /// No highlighting.
+    NoHighlight,
+
+    /// Invert the contents of the annotation rectangle.
+    InvertBox,
+
+    /// Invert the border of the annotation.
+    InvertBorder,
+
+    /// Display the down appearance of the annotation.
+    DownAppearance,
+}
+
+impl From<HighlightMode> for HPDF_AnnotHighlightMode {
+    fn from(value: HighlightMode) -> Self {
+        use HPDF_AnnotHighlightMode::*;
+        use HighlightMode::*;
+        match value {
+            NoHighlight => HPDF_ANNOT_NO_HIGHTLIGHT,
+            InvertBox => HPDF_ANNOT_INVERT_BOX,
+            InvertBorder => HPDF_ANNOT_INVERT_BORDER,
+            DownAppearance => HPDF_ANNOT_DOWN_APPEARANCE,
+        }
+    }
+}
+
+/// Icon of a text annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotIcon {
+    /// Comment
+    Comment,
+
+    /// Key
+    Key,
+
+    /// Note
+    Note,
+
+    /// Help
+    Help,
+
+    /// New paragraph
+    NewParagraph,
+
+    /// Paragraph
+    Paragraph,
+
+    /// Insert
+    Insert,
+}
+
+impl From<AnnotIcon> for HPDF_AnnotIcon {
+    fn from(value: AnnotIcon) -> Self {
+        use AnnotIcon::*;
+        use HPDF_AnnotIcon::*;
+        match value {
+            Comment => HPDF_ANNOT_ICON_COMMENT,
+            Key => HPDF_ANNOT_ICON_KEY,
+            Note => HPDF_ANNOT_ICON_NOTE,
+            Help => HPDF_ANNOT_ICON_HELP,
+            NewParagraph => HPDF_ANNOT_ICON_NEW_PARAGRAPH,
+            Paragraph => HPDF_ANNOT_ICON_PARAGRAPH,
+            Insert => HPDF_ANNOT_ICON_INSERT,
+        }
+    }
+}
+
+/// Kind of a text markup annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMarkup {
+    /// Highlight the text.
+    Highlight,
+
+    /// Underline the text.
+    Underline,
+
+    /// Draw a jagged underline below the text.
+    Squiggly,
+
+    /// Strike out the text.
+    StrikeOut,
+}
+
+/// Shape at an end of a line annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// No line ending
+    None,
+
+    /// Square
+    Square,
+
+    /// Circle
+    Circle,
+
+    /// Diamond
+    Diamond,
+
+    /// Open arrow
+    OpenArrow,
+
+    /// Closed arrow
+    ClosedArrow,
+
+    /// Short line perpendicular to the line
+    Butt,
+
+    /// Reversed open arrow
+    ReversedOpenArrow,
+
+    /// Reversed closed arrow
+    ReversedClosedArrow,
+
+    /// Short line at 30 degrees to the line
+    Slash,
+}
+
+impl From<LineEnding> for HPDF_LineAnnotEndingStyle {
+    fn from(value: LineEnding) -> Self {
+        use HPDF_LineAnnotEndingStyle::*;
+        use LineEnding::*;
+        match value {
+            None => HPDF_LINE_ANNOT_NONE,
+            Square => HPDF_LINE_ANNOT_SQUARE,
+            Circle => HPDF_LINE_ANNOT_CIRCLE,
+            Diamond => HPDF_LINE_ANNOT_DIAMOND,
+            OpenArrow => HPDF_LINE_ANNOT_OPENARROW,
+            ClosedArrow => HPDF_LINE_ANNOT_CLOSEDARROW,
+            Butt => HPDF_LINE_ANNOT_BUTT,
+            ReversedOpenArrow => HPDF_LINE_ANNOT_ROPENARROW,
+            ReversedClosedArrow => HPDF_LINE_ANNOT_RCLOSEDARROW,
+            Slash => HPDF_LINE_ANNOT_SLASH,
+        }
+    }
+}
+
+/// Quadrilateral covering the text of a text markup annotation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Quad {
+    /// Left bottom corner
+    pub left_bottom: Point,
+
+    /// Right bottom corner
+    pub right_bottom: Point,
+
+    /// Right top corner
+    pub right_top: Point,
+
+    /// Left top corner
+    pub left_top: Point,
+}
+
+impl From<Rect> for Quad {
+    fn from(v: Rect) -> Self {
+        Self {
+            left_bottom: (v.left, v.bottom).into(),
+            right_bottom: (v.right, v.bottom).into(),
+            right_top: (v.right, v.top).into(),
+            left_top: (v.left, v.top).into(),
+        }
+    }
+}
+
+impl Quad {
+    /// Get the bounding box of the quadrilateral.
+    pub fn bbox(&self) -> Rect {
+        let points = [
+            self.left_bottom,
+            self.right_bottom,
+            self.right_top,
+            self.left_top,
+        ];
+        let xs = points.iter().map(|p| p.x);
+        let ys = points.iter().map(|p| p.y);
+
+        Rect {
+            left: xs.clone().fold(Real::INFINITY, Real::min),
+            top: ys.clone().fold(Real::NEG_INFINITY, Real::max),
+            right: xs.fold(Real::NEG_INFINITY, Real::max),
+            bottom: ys.fold(Real::INFINITY, Real::min),
+        }
+    }
+}
+
+/// Border of an annotation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkBorder {
+    /// No border.
+    None,
+
+    /// Solid border.
+    Solid {
+        /// Width of the border
+        width: Real,
+    },
+
+    /// Dashed border.
+    Dashed {
+        /// Width of the border
+        width: Real,
+
+        /// Length of the dashes
+        on: u16,
+
+        /// Length of the gaps between the dashes
+        off: u16,
+    },
+}
+
+impl LinkBorder {
+    /* width, dash on and dash off lengths as libharu takes them. */
+    fn dash_pattern(&self) -> (Real, u16, u16) {
+        match *self {
+            LinkBorder::None => (0.0, 0, 0),
+            LinkBorder::Solid { width } => (width, 0, 0),
+            LinkBorder::Dashed { width, on, off } => (width, on, off),
+        }
+    }
+}
+
+/// Link annotation handle type.
+pub struct LinkAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> LinkAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+
+    /// Define the appearance when a mouse clicked on a link annotation.
+    pub fn set_highlight_mode(&self, mode: HighlightMode) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_LinkAnnot_SetHighlightMode(self.handle(), mode.into()) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_LinkAnnot_SetHighlightMode", status));
+        }
+
+        Ok(())
+    }
+
+    /// Define the style of the annotation's border.
+    pub fn set_border_style(&self, border: &LinkBorder) -> Result<()> {
+        let (width, dash_on, dash_off) = border.dash_pattern();
+
+        let status = unsafe {
+            libharu_sys::HPDF_LinkAnnot_SetBorderStyle(self.handle(), width, dash_on, dash_off)
+        };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_LinkAnnot_SetBorderStyle", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the color of the annotation's border.
+    pub fn set_border_color<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<Color>,
+    {
+        let color = color.into();
+        let color = HPDF_RGBColor {
+            r: color.red,
+            g: color.green,
+            b: color.blue,
+        };
+
+        let status = unsafe { libharu_sys::HPDF_Annot_SetRGBColor(self.handle(), color) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Annot_SetRGBColor", status));
+        }
+
+        Ok(())
+    }
+}
+
+mod sealed {
+    /* the handle is passed to libharu, so only annotations of this crate may implement it. */
+    pub trait Sealed {
+        fn handle(&self) -> libharu_sys::HPDF_Annotation;
+    }
+}
+
+/// Functions shared by markup annotations.
+///
+/// This trait is sealed: it is only implemented by the annotation types of this crate.
+pub trait MarkupAnnotation: Sealed {
+    /// Get the page of the annotation.
+    fn page(&self) -> &Page;
+
+    /// Set the color of the annotation.
+    fn set_color<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<Color>,
+    {
+        let color = color.into();
+        let color = HPDF_RGBColor {
+            r: color.red,
+            g: color.green,
+            b: color.blue,
+        };
+
+        let status = unsafe { libharu_sys::HPDF_Annot_SetRGBColor(self.handle(), color) };
+
+        if status != 0 {
+            return Err(self
+                .page()
+                .doc()
+                .status_error("HPDF_Annot_SetRGBColor", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the author of the annotation, shown as the title of its pop-up window.
+    fn set_author(&self, author: &str) -> Result<()> {
+        let author = CString::new(author)?;
+        let status =
+            unsafe { libharu_sys::HPDF_MarkupAnnot_SetTitle(self.handle(), author.as_ptr()) };
+
+        if status != 0 {
+            return Err(self
+                .page()
+                .doc()
+                .status_error("HPDF_MarkupAnnot_SetTitle", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the subject of the annotation.
+    fn set_subject(&self, subject: &str) -> Result<()> {
+        let subject = CString::new(subject)?;
+        let status =
+            unsafe { libharu_sys::HPDF_MarkupAnnot_SetSubject(self.handle(), subject.as_ptr()) };
+
+        if status != 0 {
+            return Err(self
+                .page()
+                .doc()
+                .status_error("HPDF_MarkupAnnot_SetSubject", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the date and time the annotation was created.
+    fn set_creation_date(&self, date: Date) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_MarkupAnnot_SetCreationDate(self.handle(), date.into()) };
+
+        if status != 0 {
+            return Err(self
+                .page()
+                .doc()
+                .status_error("HPDF_MarkupAnnot_SetCreationDate", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the opacity of the annotation. (0.0 ~ 1.0)
+    fn set_opacity(&self, opacity: Real) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_MarkupAnnot_SetTransparency(self.handle(), opacity) };
+
+        if status != 0 {
+            return Err(self
+                .page()
+                .doc()
+                .status_error("HPDF_MarkupAnnot_SetTransparency", status));
+        }
+
+        Ok(())
+    }
+
+    /// Define the style of the annotation's border.
+    fn set_border(&self, border: &LinkBorder) -> Result<()> {
+        let (width, dash_on, dash_off) = border.dash_pattern();
+        let subtype = match border {
+            LinkBorder::Dashed { .. } => HPDF_BSSubtype::HPDF_BS_DASHED,
+            _ => HPDF_BSSubtype::HPDF_BS_SOLID,
+        };
+
+        let status = unsafe {
+            libharu_sys::HPDF_Annotation_SetBorderStyle(
+                self.handle(),
+                subtype,
+                width,
+                dash_on,
+                dash_off,
+                0,
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .page()
+                .doc()
+                .status_error("HPDF_Annotation_SetBorderStyle", status));
+        }
+
+        Ok(())
+    }
+}
+
+/// Text annotation handle type.
+pub struct TextAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> TextAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    /// Define the icon of the annotation.
+    pub fn set_icon(&self, icon: AnnotIcon) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_TextAnnot_SetIcon(self.handle(), icon.into()) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_TextAnnot_SetIcon", status));
+        }
+
+        Ok(())
+    }
+
+    /// Define whether the annotation is initially displayed open.
+    pub fn set_opened(&self, opened: bool) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_TextAnnot_SetOpened(self.handle(), if opened { 1 } else { 0 })
+        };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_TextAnnot_SetOpened", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'page> Sealed for TextAnnotation<'page> {
+    fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+}
+
+impl<'page> MarkupAnnotation for TextAnnotation<'page> {
+    fn page(&self) -> &Page {
+        self.page
+    }
+}
+
+/// Text markup (highlight, underline, squiggly or strike out) annotation handle type.
+pub struct TextMarkupAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> TextMarkupAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    /// Set the quadrilateral covering the marked up text.
+    pub fn set_quad(&self, quad: Quad) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_TextMarkupAnnot_SetQuadPoints(
+                self.handle(),
+                HPDF_Point::from(quad.left_bottom),
+                HPDF_Point::from(quad.right_bottom),
+                HPDF_Point::from(quad.right_top),
+                HPDF_Point::from(quad.left_top),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_TextMarkupAnnot_SetQuadPoints", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'page> Sealed for TextMarkupAnnotation<'page> {
+    fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+}
+
+impl<'page> MarkupAnnotation for TextMarkupAnnotation<'page> {
+    fn page(&self) -> &Page {
+        self.page
+    }
+}
+
+/// Square or circle annotation handle type.
+pub struct ShapeAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> ShapeAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    /// Set the color used to fill the shape.
+    pub fn set_interior_color<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<Color>,
+    {
+        let color = color.into();
+        let color = HPDF_RGBColor {
+            r: color.red,
+            g: color.green,
+            b: color.blue,
+        };
+
+        let status =
+            unsafe { libharu_sys::HPDF_MarkupAnnot_SetInteriorRGBColor(self.handle(), color) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_MarkupAnnot_SetInteriorRGBColor", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'page> Sealed for ShapeAnnotation<'page> {
+    fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+}
+
+impl<'page> MarkupAnnotation for ShapeAnnotation<'page> {
+    fn page(&self) -> &Page {
+        self.page
+    }
+}
+
+/// Line annotation handle type.
+pub struct LineAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> LineAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    /// Set the end points of the line and the shapes drawn at them.
+    pub fn set_position<T1, T2>(
+        &self,
+        start: T1,
+        start_style: LineEnding,
+        end: T2,
+        end_style: LineEnding,
+    ) -> Result<()>
+    where
+        T1: Into<Point>,
+        T2: Into<Point>,
+    {
+        let status = unsafe {
+            libharu_sys::HPDF_LineAnnot_SetPosition(
+                self.handle(),
+                HPDF_Point::from(start.into()),
+                start_style.into(),
+                HPDF_Point::from(end.into()),
+                end_style.into(),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_LineAnnot_SetPosition", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'page> Sealed for LineAnnotation<'page> {
+    fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+}
+
+impl<'page> MarkupAnnotation for LineAnnotation<'page> {
+    fn page(&self) -> &Page {
+        self.page
+    }
+}
+
+/// Free text annotation handle type.
+pub struct FreeTextAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> FreeTextAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    /// Set the default style string used to format the text. (e.g. `font: 12pt Helvetica`)
+    pub fn set_default_style(&self, style: &str) -> Result<()> {
+        let style = CString::new(style)?;
+        let status = unsafe {
+            libharu_sys::HPDF_FreeTextAnnot_SetDefaultStyle(self.handle(), style.as_ptr())
+        };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_FreeTextAnnot_SetDefaultStyle", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'page> Sealed for FreeTextAnnotation<'page> {
+    fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+}
+
+impl<'page> MarkupAnnotation for FreeTextAnnotation<'page> {
+    fn page(&self) -> &Page {
+        self.page
+    }
+}
+
+/// Popup annotation handle type.
+pub struct PopupAnnotation<'page> {
+    annot: libharu_sys::HPDF_Annotation,
+    page: &'page Page<'page>,
+}
+
+impl<'page> PopupAnnotation<'page> {
+    pub(crate) fn new(page: &'page Page<'page>, annot: libharu_sys::HPDF_Annotation) -> Self {
+        Self { annot, page }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Annotation {
+        self.annot
+    }
+
+    /// Define whether the pop-up window is initially displayed open.
+    pub fn set_opened(&self, opened: bool) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_PopupAnnot_SetOpened(self.handle(), if opened { 1 } else { 0 })
+        };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_PopupAnnot_SetOpened", status));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::document::Document;
+
+/// Content stream handle type, holding drawing operators which can be shared by pages.
+///
+/// Created by `Page::new_content_stream` or `Page::shared_content`, and added to
+/// other pages with `Page::insert_shared_content_stream`. The stream is written once
+/// in the document however many pages show it.
+///
+/// The stream refers to fonts, images and graphics states by the names they have in
+/// the resources of the page it was drawn on, which libharu gives in the order they
+/// are first used. A page inserting the stream must use the same fonts, images and
+/// graphics states in the same order before inserting it, e.g. by calling
+/// `set_font_and_size` with each font. Paths and colors need no resources.
+pub struct ContentStream<'a> {
+    stream: libharu_sys::HPDF_Dict,
+    _doc: &'a Document,
+}
+
+impl<'a> ContentStream<'a> {
+    pub(crate) fn new(doc: &'a Document, stream: libharu_sys::HPDF_Dict) -> Self {
+        Self { stream, _doc: doc }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Dict {
+        self.stream
+    }
+}
@@ -1,948 +1,1330 @@
-use crate::prelude::*; //{Page, Rect, Color, CmykColor, Real, Font, Point, LineCap, LineJoin, TextRenderingMode, TextAlignment};
-use std::ffi::CString;
-use std::ops::Deref;
-
-/// Page functions in Description mode or Text mode.
-pub trait PageDescTeextCommonFunction<'doc>: Deref<Target = Page<'doc>> {
-    /// Get Page
-    fn handle(&self) -> &Page;
-
-    /// Set line width of page.
-    fn set_line_width(&self, width: Real) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_SetLineWidth(self.handle().handle(), width) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetLineWidth failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the shape to be used at the ends of line.
-    fn set_line_cap(&self, line_cap: LineCap) -> anyhow::Result<()> {
-        let line_cap = match line_cap {
-            LineCap::Butt => libharu_sys::HPDF_LineCap::HPDF_BUTT_END,
-            LineCap::Round => libharu_sys::HPDF_LineCap::HPDF_ROUND_END,
-            LineCap::ProjectingSquare => libharu_sys::HPDF_LineCap::HPDF_PROJECTING_SCUARE_END,
-        };
-
-        let status = unsafe { libharu_sys::HPDF_Page_SetLineCap(self.handle().handle(), line_cap) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetLineCap failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the line join style in the page.
-    fn set_line_join(&self, line_join: LineJoin) -> anyhow::Result<()> {
-        let line_join = match line_join {
-            LineJoin::Miter => libharu_sys::HPDF_LineJoin::HPDF_MITER_JOIN,
-            LineJoin::Round => libharu_sys::HPDF_LineJoin::HPDF_ROUND_JOIN,
-            LineJoin::Bevel => libharu_sys::HPDF_LineJoin::HPDF_BEVEL_JOIN,
-        };
-
-        let status =
-            unsafe { libharu_sys::HPDF_Page_SetLineJoin(self.handle().handle(), line_join) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetLineJoin failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the line dash pattern in the page.
-    fn set_dash(&self, dash_mode: &[u16], phase: usize) -> anyhow::Result<()> {
-        let status = unsafe {
-            libharu_sys::HPDF_Page_SetDash(
-                self.handle().handle(),
-                dash_mode.as_ptr(),
-                dash_mode.len() as u32,
-                phase as u32,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetDash failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the character spacing for text showing.
-    fn set_char_space(&self, value: Real) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_SetCharSpace(self.handle().handle(), value) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetCharSpace failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the word spacing for text showing.
-    fn get_word_space(&self) -> anyhow::Result<f32> {
-        let result = unsafe { libharu_sys::HPDF_Page_GetWordSpace(self.handle().handle()) };
-        Ok(result)
-    }
-
-    /// Set the word spacing for text showing.
-    fn set_word_space(&self, value: Real) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_SetWordSpace(self.handle().handle(), value) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetWordSpace failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the horizontal scalling for text showing.
-    fn set_horizontal_scalling(&self, value: Real) -> anyhow::Result<()> {
-        let status =
-            unsafe { libharu_sys::HPDF_Page_SetHorizontalScalling(self.handle().handle(), value) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetHorizontalScalling failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set text leading
-    fn set_text_leading(&self, value: Real) -> anyhow::Result<()> {
-        let status =
-            unsafe { libharu_sys::HPDF_Page_SetTextLeading(self.handle().handle(), value) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetTextLeading failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set font and size.
-    fn set_font_and_size(&self, font: &Font, size: Real) -> anyhow::Result<()> {
-        let status = unsafe {
-            libharu_sys::HPDF_Page_SetFontAndSize(self.handle().handle(), font.font, size)
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetFontAndSize failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Sets the text rendering mode.
-    fn set_text_rendering_mode(&self, mode: TextRenderingMode) -> anyhow::Result<()> {
-        let mode = match mode {
-            TextRenderingMode::Fill => libharu_sys::HPDF_TextRenderingMode::HPDF_FILL,
-            TextRenderingMode::Stroke => libharu_sys::HPDF_TextRenderingMode::HPDF_STROKE,
-            TextRenderingMode::FillThenStroke => {
-                libharu_sys::HPDF_TextRenderingMode::HPDF_FILL_THEN_STROKE
-            }
-            TextRenderingMode::Invisible => libharu_sys::HPDF_TextRenderingMode::HPDF_INVISIBLE,
-            TextRenderingMode::FillClipping => {
-                libharu_sys::HPDF_TextRenderingMode::HPDF_FILL_CLIPPING
-            }
-            TextRenderingMode::StrokeClipping => {
-                libharu_sys::HPDF_TextRenderingMode::HPDF_STROKE_CLIPPING
-            }
-            TextRenderingMode::FillStrokeClipping => {
-                libharu_sys::HPDF_TextRenderingMode::HPDF_FILL_STROKE_CLIPPING
-            }
-            TextRenderingMode::Clipping => libharu_sys::HPDF_TextRenderingMode::HPDF_CLIPPING,
-        };
-
-        let status =
-            unsafe { libharu_sys::HPDF_Page_SetTextRenderingMode(self.handle().handle(), mode) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetTextRenderingMode failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Print the text at the current position on the page.
-    fn show_text(&self, text: &str) -> anyhow::Result<()> {
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_ShowText(
-                self.handle().handle(),
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_ShowText failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Print the text at the current position on the page. (bytes data)
-    fn show_text_bytes(&self, text: &[u8]) -> anyhow::Result<()> {
-        let text = CString::new(text).unwrap();
-        let status = unsafe {
-            libharu_sys::HPDF_Page_ShowText(
-                self.handle().handle(),
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_ShowText failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Move the current text position to the start of the next line,
-    fn show_text_next_line(&self, text: &str) -> anyhow::Result<()> {
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_ShowTextNextLine(
-                self.handle().handle(),
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_ShowTextNextLine failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Move the current text position to the start of the next line, (bytes data)
-    fn show_text_next_line_bytes(&self, text: &[u8]) -> anyhow::Result<()> {
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_ShowTextNextLine(
-                self.handle().handle(),
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_ShowTextNextLine failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Moves the current text position to the start of the next line, then sets the word spacing,
-    /// character spacing and prints the text at the current position on the page.
-    fn show_text_next_line_ex(
-        &self,
-        word_space: Real,
-        char_space: Real,
-        text: &str,
-    ) -> anyhow::Result<()> {
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_ShowTextNextLineEx(
-                self.handle().handle(),
-                word_space,
-                char_space,
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_ShowTextNextLineEx failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Moves the current text position to the start of the next line, then sets the word spacing,
-    /// character spacing and prints the text at the current position on the page. (bytes data)
-    fn show_text_next_line_ex_bytes(
-        &self,
-        word_space: Real,
-        char_space: Real,
-        text: &[u8],
-    ) -> anyhow::Result<()> {
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_ShowTextNextLineEx(
-                self.handle().handle(),
-                word_space,
-                char_space,
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_ShowTextNextLineEx failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the filling color.
-    fn set_gray_fill(&self, gray: Real) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_SetGrayFill(self.handle().handle(), gray) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetGrayFill failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the stroking color.
-    fn set_gray_stroke(&self, gray: Real) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_SetGrayStroke(self.handle().handle(), gray) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetGrayStroke failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set filling color.
-    fn set_rgb_fill<T>(&self, color: T) -> anyhow::Result<()>
-    where
-        T: Into<Color>,
-    {
-        let color = color.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_SetRGBFill(
-                self.handle().handle(),
-                color.red,
-                color.green,
-                color.blue,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetRGBFill failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the stroking color.
-    fn set_rgb_stroke<T>(&self, color: T) -> anyhow::Result<()>
-    where
-        T: Into<Color>,
-    {
-        let color = color.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_SetRGBStroke(
-                self.handle().handle(),
-                color.red,
-                color.green,
-                color.blue,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetRGBStroke failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the filling color.
-    fn set_cmyk_fill<T>(&self, color: T) -> anyhow::Result<()>
-    where
-        T: Into<CmykColor>,
-    {
-        let color = color.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_SetCMYKFill(
-                self.handle().handle(),
-                color.cyan,
-                color.magenta,
-                color.yellow,
-                color.keyplate,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetCMYKFill failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set the stroking color.
-    fn set_cmyk_stroke<T>(&self, color: T) -> anyhow::Result<()>
-    where
-        T: Into<CmykColor>,
-    {
-        let color = color.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_SetCMYKStroke(
-                self.handle().handle(),
-                color.cyan,
-                color.magenta,
-                color.yellow,
-                color.keyplate,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetCMYKStroke failed (status={})", status);
-        }
-
-        Ok(())
-    }
-}
-
-//------------------------------------------------------------------------------
-/// Page functions in Description mode or Path mode.
-pub trait PageDescPathCommonFunction<'doc>: Deref<Target = Page<'doc>> {
-    /// Get Page
-    fn handle(&self) -> &Page;
-}
-
-//--------------------------------------------------------------------------
-/// Page object in Description mode.
-pub struct PageDescriptionMode<'doc, 'page> {
-    page: &'page Page<'doc>,
-}
-
-impl<'doc, 'page> PageDescriptionMode<'doc, 'page> {
-    /// Create new PageDescriptionMode instance.
-    pub fn new(page: &'page Page<'doc>) -> Self {
-        Self { page }
-    }
-
-    fn begin_text(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_BeginText(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_BeginText failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    pub(crate) fn end_text(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_EndText(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_TextOut failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enter text mode.
-    pub fn run_text_mode<F>(&self, f: F) -> anyhow::Result<()>
-    where
-        F: FnOnce(&PageTextMode) -> anyhow::Result<()>,
-    {
-        self.begin_text()?;
-        let page = PageTextMode::new(self.page);
-        let ret = f(&page);
-        self.end_text()?;
-
-        ret
-    }
-    /*
-        fn end_path(&self) -> anyhow::Result<()> {
-            let status = unsafe {
-                libharu_sys::HPDF_Page_EndPath(self.page.handle())
-            };
-
-            if status != 0 {
-                anyhow::bail!("HPDF_Page_EndPath failed (status={})", status);
-            }
-            Ok(())
-        }
-    */
-    /// Enter path mode.
-    pub fn run_path_mode<F>(&self, f: F) -> anyhow::Result<()>
-    where
-        F: FnOnce(&PagePathMode) -> anyhow::Result<()>,
-    {
-        let page = PagePathMode::new(self.page);
-
-        // f()内のstroke(), fill()などの呼び出しでDESCRIPTIONモードに戻る。
-        // 呼び忘れた場合にここでDESCRIPTIONモードに戻したいがうまくいかない。
-        // end_pathだとすでにDESCRIPTIONモードだったときにGStateが壊れる。
-        //let _ = self.end_path();
-
-        f(&page)
-    }
-}
-
-impl<'doc, 'page> Deref for PageDescriptionMode<'doc, 'page> {
-    type Target = Page<'doc>;
-    fn deref(&self) -> &Self::Target {
-        self.page
-    }
-}
-
-impl<'doc, 'page> PageDescTeextCommonFunction<'doc> for PageDescriptionMode<'doc, 'page> {
-    fn handle(&self) -> &Page {
-        self.page
-    }
-}
-
-impl<'doc, 'page> PageDescPathCommonFunction<'doc> for PageDescriptionMode<'doc, 'page> {
-    fn handle(&self) -> &Page {
-        self.page
-    }
-}
-
-//--------------------------------------------------------------------------------------
-
-/// Page object in text mode.
-pub struct PageTextMode<'doc, 'page> {
-    page: &'page Page<'doc>,
-}
-
-impl<'doc, 'page> PageTextMode<'doc, 'page> {
-    pub(crate) fn new(page: &'page Page<'doc>) -> Self {
-        Self { page }
-    }
-
-    /// Move the current text position to the start of the next line with using specified offset values.
-    pub fn move_text_pos<T>(&self, pos: T) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-
-        let status =
-            unsafe { libharu_sys::HPDF_Page_MoveTextPos(self.page.handle(), pos.x, pos.y) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_MoveTextPos failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Move the current text position to the start of the next line with using specified offset values.
-    pub fn move_text_pos2<T>(&self, pos: T) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-
-        let status =
-            unsafe { libharu_sys::HPDF_Page_MoveTextPos2(self.page.handle(), pos.x, pos.y) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_MoveTextPos2 failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set text affine transformation matrix.
-    #[allow(clippy::many_single_char_names)]
-    pub fn set_text_matrix(
-        &self,
-        a: Real,
-        b: Real,
-        c: Real,
-        d: Real,
-        x: Real,
-        y: Real,
-    ) -> anyhow::Result<()> {
-        let status =
-            unsafe { libharu_sys::HPDF_Page_SetTextMatrix(self.page.handle(), a, b, c, d, x, y) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_SetTextRenderingMode failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Move the current text position to the start of the next line with using specified offset values.
-    pub fn move_to_next_line(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_MoveToNextLine(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_MoveToNextLine failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Print the text on the specified position.
-    pub fn text_out<T>(&self, pos: T, text: &str) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_TextOut(
-                self.page.handle(),
-                pos.x,
-                pos.y,
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_TextOut failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Print the text on the specified position. (bytes data)
-    pub fn text_out_bytes<T>(&self, pos: T, text: &[u8]) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-        let text = CString::new(text)?;
-        let status = unsafe {
-            libharu_sys::HPDF_Page_TextOut(
-                self.page.handle(),
-                pos.x,
-                pos.y,
-                std::mem::transmute(text.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_TextOut failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Print the text inside the specified region.
-    pub fn text_rect<T>(&self, rect: T, text: &str, align: TextAlignment) -> anyhow::Result<()>
-    where
-        T: Into<Rect>,
-    {
-        let rect = rect.into();
-        let text = CString::new(text)?;
-        let align = match align {
-            TextAlignment::Left => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_LEFT,
-            TextAlignment::Right => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_RIGHT,
-            TextAlignment::Center => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_CENTER,
-            TextAlignment::Justify => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_JUSTIFY,
-        };
-        let mut len = 0;
-        let _status = unsafe {
-            libharu_sys::HPDF_Page_TextRect(
-                self.page.handle(),
-                rect.left,
-                rect.top,
-                rect.right,
-                rect.bottom,
-                std::mem::transmute(text.as_ptr()),
-                align,
-                &mut len,
-            )
-        };
-
-        //if status != 0 {
-        //    anyhow::bail!("HPDF_Page_TextRect failed (status={})", status);
-        //}
-
-        Ok(())
-    }
-
-    /// Print the text inside the specified region. (byte data)
-    pub fn text_rect_bytes<T>(
-        &self,
-        rect: T,
-        text: &[u8],
-        align: TextAlignment,
-    ) -> anyhow::Result<()>
-    where
-        T: Into<Rect>,
-    {
-        let rect = rect.into();
-        let text = CString::new(text)?;
-        let align = match align {
-            TextAlignment::Left => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_LEFT,
-            TextAlignment::Right => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_RIGHT,
-            TextAlignment::Center => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_CENTER,
-            TextAlignment::Justify => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_JUSTIFY,
-        };
-        let mut len = 0;
-        let _status = unsafe {
-            libharu_sys::HPDF_Page_TextRect(
-                self.page.handle(),
-                rect.left,
-                rect.top,
-                rect.right,
-                rect.bottom,
-                std::mem::transmute(text.as_ptr()),
-                align,
-                &mut len,
-            )
-        };
-
-        //if status != 0 {
-        //    anyhow::bail!("HPDF_Page_TextRect failed (status={})", status);
-        //}
-
-        Ok(())
-    }
-}
-
-impl<'doc, 'page> Deref for PageTextMode<'doc, 'page> {
-    type Target = Page<'doc>;
-    fn deref(&self) -> &Self::Target {
-        self.page
-    }
-}
-
-impl<'doc, 'page> PageDescTeextCommonFunction<'doc> for PageTextMode<'doc, 'page> {
-    fn handle(&self) -> &Page {
-        self.page
-    }
-}
-
-//-------------------------------------------------------------------------------------------
-
-/// Page object in Path mode.
-pub struct PagePathMode<'doc, 'page> {
-    page: &'page Page<'doc>,
-}
-
-impl<'doc, 'page> PagePathMode<'doc, 'page> {
-    pub(crate) fn new(page: &'page Page<'doc>) -> Self {
-        Self { page }
-    }
-
-    /// Start a new subpath and move the current point for drawing path,
-    pub fn move_to<T>(&self, pos: T) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-
-        let status = unsafe { libharu_sys::HPDF_Page_MoveTo(self.page.handle(), pos.x, pos.y) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_MoveTo failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a Bézier curve to the current path using three spesified points.
-    pub fn curve_to<T1, T2, T3>(&self, point1: T1, point2: T2, point3: T3) -> anyhow::Result<()>
-    where
-        T1: Into<Point>,
-        T2: Into<Point>,
-        T3: Into<Point>,
-    {
-        let point1 = point1.into();
-        let point2 = point2.into();
-        let point3 = point3.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_CurveTo(
-                self.page.handle(),
-                point1.x,
-                point1.y,
-                point2.x,
-                point2.y,
-                point3.x,
-                point3.y,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_CurveTo failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a Bézier curve to the current path using two spesified points.
-    pub fn curve_to_2<T1, T2>(&self, point2: T1, point3: T2) -> anyhow::Result<()>
-    where
-        T1: Into<Point>,
-        T2: Into<Point>,
-    {
-        let point2 = point2.into();
-        let point3 = point3.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_CurveTo2(
-                self.page.handle(),
-                point2.x,
-                point2.y,
-                point3.x,
-                point3.y,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_CurveTo2 failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a Bézier curve to the current path using two spesified points.
-    pub fn curve_to_3<T1, T2>(&self, point1: T1, point3: T2) -> anyhow::Result<()>
-    where
-        T1: Into<Point>,
-        T2: Into<Point>,
-    {
-        let point1 = point1.into();
-        let point3 = point3.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_CurveTo3(
-                self.page.handle(),
-                point1.x,
-                point1.y,
-                point3.x,
-                point3.y,
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_CurveTo3 failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a path from the current point to the specified point.
-    pub fn line_to<T>(&self, pos: T) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-        let status = unsafe { libharu_sys::HPDF_Page_LineTo(self.page.handle(), pos.x, pos.y) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_LineTo failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a rectangle to the current path.
-    pub fn rectangle<T>(&self, pos: T, width: Real, height: Real) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_Rectangle(self.page.handle(), pos.x, pos.y, width, height)
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_Rectangle failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a circle to the current path.
-    pub fn circle<T>(&self, pos: T, ray: Real) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-
-        let status =
-            unsafe { libharu_sys::HPDF_Page_Circle(self.page.handle(), pos.x, pos.y, ray) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_Circle failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Append a arc to the current path.
-    pub fn arc<T>(&self, pos: T, ray: Real, ang1: Real, ang2: Real) -> anyhow::Result<()>
-    where
-        T: Into<Point>,
-    {
-        let pos = pos.into();
-
-        let status = unsafe {
-            libharu_sys::HPDF_Page_Arc(self.page.handle(), pos.x, pos.y, ray, ang1, ang2)
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_Arc failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Paint the current path.
-    pub fn stroke(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_Stroke(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_Stroke failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Fill the current path using the nonzero winding number rule.
-    pub fn fill(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_Fill(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_Fill failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Fill the current path using the nonzero winding number rule, then paint the current path.
-    pub fn fill_stroke(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_FillStroke(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_FillStroke failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Paint the current path and set clipping region.
-    pub fn clip(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_Page_Clip(self.page.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Page_Clip failed (status={})", status);
-        }
-
-        Ok(())
-    }
-}
-
-impl<'doc, 'page> Deref for PagePathMode<'doc, 'page> {
-    type Target = Page<'doc>;
-    fn deref(&self) -> &Self::Target {
-        self.page
-    }
-}
-
-impl<'doc, 'page> PageDescPathCommonFunction<'doc> for PagePathMode<'doc, 'page> {
-    fn handle(&self) -> &Page {
-        self.page
-    }
-}
+use crate::prelude::*; //{Page, Rect, Color, CmykColor, Real, Font, Point, LineCap, LineJoin, TextRenderingMode, TextAlignment};
+use crate::svg_path::{self, Segment};
+use crate::Result;
+use std::ffi::CString;
+use std::ops::Deref;
+
+/// Page functions in Description mode or Text mode.
+pub trait PageDescTeextCommonFunction<'doc>: Deref<Target = Page<'doc>> {
+    /// Get Page
+    fn handle(&self) -> &Page;
+
+    /// Set line width of page.
+    fn set_line_width(&self, width: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_SetLineWidth(self.handle().handle(), width) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetLineWidth", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the shape to be used at the ends of line.
+    fn set_line_cap(&self, line_cap: LineCap) -> Result<()> {
+        let line_cap = match line_cap {
+            LineCap::Butt => libharu_sys::HPDF_LineCap::HPDF_BUTT_END,
+            LineCap::Round => libharu_sys::HPDF_LineCap::HPDF_ROUND_END,
+            LineCap::ProjectingSquare => libharu_sys::HPDF_LineCap::HPDF_PROJECTING_SCUARE_END,
+        };
+
+        let status = unsafe { libharu_sys::HPDF_Page_SetLineCap(self.handle().handle(), line_cap) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetLineCap", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the line join style in the page.
+    fn set_line_join(&self, line_join: LineJoin) -> Result<()> {
+        let line_join = match line_join {
+            LineJoin::Miter => libharu_sys::HPDF_LineJoin::HPDF_MITER_JOIN,
+            LineJoin::Round => libharu_sys::HPDF_LineJoin::HPDF_ROUND_JOIN,
+            LineJoin::Bevel => libharu_sys::HPDF_LineJoin::HPDF_BEVEL_JOIN,
+        };
+
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetLineJoin(self.handle().handle(), line_join) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetLineJoin", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the line dash pattern in the page.
+    fn set_dash(&self, dash_mode: &[u16], phase: usize) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_Page_SetDash(
+                self.handle().handle(),
+                dash_mode.as_ptr(),
+                dash_mode.len() as u32,
+                phase as u32,
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetDash", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the character spacing for text showing.
+    fn set_char_space(&self, value: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_SetCharSpace(self.handle().handle(), value) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetCharSpace", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the word spacing for text showing.
+    fn get_word_space(&self) -> Result<f32> {
+        let result = unsafe { libharu_sys::HPDF_Page_GetWordSpace(self.handle().handle()) };
+        Ok(result)
+    }
+
+    /// Set the word spacing for text showing.
+    fn set_word_space(&self, value: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_SetWordSpace(self.handle().handle(), value) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetWordSpace", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the horizontal scalling for text showing.
+    fn set_horizontal_scalling(&self, value: Real) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetHorizontalScalling(self.handle().handle(), value) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetHorizontalScalling", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set text leading
+    fn set_text_leading(&self, value: Real) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetTextLeading(self.handle().handle(), value) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetTextLeading", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set font and size.
+    fn set_font_and_size(&self, font: &Font, size: Real) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_Page_SetFontAndSize(self.handle().handle(), font.font, size)
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetFontAndSize", status));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the text rendering mode.
+    fn set_text_rendering_mode(&self, mode: TextRenderingMode) -> Result<()> {
+        let mode = match mode {
+            TextRenderingMode::Fill => libharu_sys::HPDF_TextRenderingMode::HPDF_FILL,
+            TextRenderingMode::Stroke => libharu_sys::HPDF_TextRenderingMode::HPDF_STROKE,
+            TextRenderingMode::FillThenStroke => {
+                libharu_sys::HPDF_TextRenderingMode::HPDF_FILL_THEN_STROKE
+            }
+            TextRenderingMode::Invisible => libharu_sys::HPDF_TextRenderingMode::HPDF_INVISIBLE,
+            TextRenderingMode::FillClipping => {
+                libharu_sys::HPDF_TextRenderingMode::HPDF_FILL_CLIPPING
+            }
+            TextRenderingMode::StrokeClipping => {
+                libharu_sys::HPDF_TextRenderingMode::HPDF_STROKE_CLIPPING
+            }
+            TextRenderingMode::FillStrokeClipping => {
+                libharu_sys::HPDF_TextRenderingMode::HPDF_FILL_STROKE_CLIPPING
+            }
+            TextRenderingMode::Clipping => libharu_sys::HPDF_TextRenderingMode::HPDF_CLIPPING,
+        };
+
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetTextRenderingMode(self.handle().handle(), mode) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetTextRenderingMode", status));
+        }
+
+        Ok(())
+    }
+
+    /// Print the text at the current position on the page.
+    fn show_text(&self, text: &str) -> Result<()> {
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_ShowText(
+                self.handle().handle(),
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_ShowText", status));
+        }
+
+        Ok(())
+    }
+
+    /// Print the text at the current position on the page. (bytes data)
+    fn show_text_bytes(&self, text: &[u8]) -> Result<()> {
+        let text = CString::new(text).unwrap();
+        let status = unsafe {
+            libharu_sys::HPDF_Page_ShowText(
+                self.handle().handle(),
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_ShowText", status));
+        }
+
+        Ok(())
+    }
+
+    /// Move the current text position to the start of the next line,
+    fn show_text_next_line(&self, text: &str) -> Result<()> {
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_ShowTextNextLine(
+                self.handle().handle(),
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_ShowTextNextLine", status));
+        }
+
+        Ok(())
+    }
+
+    /// Move the current text position to the start of the next line, (bytes data)
+    fn show_text_next_line_bytes(&self, text: &[u8]) -> Result<()> {
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_ShowTextNextLine(
+                self.handle().handle(),
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_ShowTextNextLine", status));
+        }
+
+        Ok(())
+    }
+
+    /// Moves the current text position to the start of the next line, then sets the word spacing,
+    /// character spacing and prints the text at the current position on the page.
+    fn show_text_next_line_ex(&self, word_space: Real, char_space: Real, text: &str) -> Result<()> {
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_ShowTextNextLineEx(
+                self.handle().handle(),
+                word_space,
+                char_space,
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_ShowTextNextLineEx", status));
+        }
+
+        Ok(())
+    }
+
+    /// Moves the current text position to the start of the next line, then sets the word spacing,
+    /// character spacing and prints the text at the current position on the page. (bytes data)
+    fn show_text_next_line_ex_bytes(
+        &self,
+        word_space: Real,
+        char_space: Real,
+        text: &[u8],
+    ) -> Result<()> {
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_ShowTextNextLineEx(
+                self.handle().handle(),
+                word_space,
+                char_space,
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_ShowTextNextLineEx", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the filling color.
+    fn set_gray_fill(&self, gray: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_SetGrayFill(self.handle().handle(), gray) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetGrayFill", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the stroking color.
+    fn set_gray_stroke(&self, gray: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_SetGrayStroke(self.handle().handle(), gray) };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetGrayStroke", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set filling color.
+    fn set_rgb_fill<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<Color>,
+    {
+        let color = color.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_SetRGBFill(
+                self.handle().handle(),
+                color.red,
+                color.green,
+                color.blue,
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetRGBFill", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the stroking color.
+    fn set_rgb_stroke<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<Color>,
+    {
+        let color = color.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_SetRGBStroke(
+                self.handle().handle(),
+                color.red,
+                color.green,
+                color.blue,
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetRGBStroke", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the filling color.
+    fn set_cmyk_fill<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<CmykColor>,
+    {
+        let color = color.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_SetCMYKFill(
+                self.handle().handle(),
+                color.cyan,
+                color.magenta,
+                color.yellow,
+                color.keyplate,
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetCMYKFill", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the stroking color.
+    fn set_cmyk_stroke<T>(&self, color: T) -> Result<()>
+    where
+        T: Into<CmykColor>,
+    {
+        let color = color.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_SetCMYKStroke(
+                self.handle().handle(),
+                color.cyan,
+                color.magenta,
+                color.yellow,
+                color.keyplate,
+            )
+        };
+
+        if status != 0 {
+            return Err(self
+                .handle()
+                .doc()
+                .status_error("HPDF_Page_SetCMYKStroke", status));
+        }
+
+        Ok(())
+    }
+}
+
+//------------------------------------------------------------------------------
+/// Page functions in Description mode or Path mode.
+pub trait PageDescPathCommonFunction<'doc>: Deref<Target = Page<'doc>> {
+    /// Get Page
+    fn handle(&self) -> &Page;
+}
+
+//--------------------------------------------------------------------------
+/// Page object in Description mode.
+pub struct PageDescriptionMode<'doc, 'page> {
+    page: &'page Page<'doc>,
+}
+
+impl<'doc, 'page> PageDescriptionMode<'doc, 'page> {
+    /// Create new PageDescriptionMode instance.
+    pub fn new(page: &'page Page<'doc>) -> Self {
+        Self { page }
+    }
+
+    fn begin_text(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_BeginText(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_BeginText", status));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn end_text(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_EndText(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_EndText", status));
+        }
+
+        Ok(())
+    }
+
+    /// Apply the extended graphics state to the page.
+    pub fn set_ext_gstate(&self, gstate: &ExtGState) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetExtGState(self.page.handle(), gstate.handle()) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Page_SetExtGState", status));
+        }
+
+        Ok(())
+    }
+
+    /// Paint the shading over the current clipping region.
+    pub fn paint_shading(&self, shading: &Shading) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetShading(self.page.handle(), shading.handle()) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_SetShading", status));
+        }
+
+        Ok(())
+    }
+
+    /// Fill the path built by the closure with the shading, using the given fill rule.
+    ///
+    /// The path is used as clipping path inside a saved graphics state, so
+    /// neither the path nor the clipping outlives the call.
+    pub fn fill_with_shading<F>(&self, shading: &Shading, rule: FillRule, f: F) -> Result<()>
+    where
+        F: FnOnce(&PagePathMode) -> Result<()>,
+    {
+        self.with_saved_state(|page| {
+            page.run_path_mode(|page| {
+                f(page)?;
+                page.clip(rule)?;
+                page.end_path()
+            })?;
+            page.paint_shading(shading)
+        })
+    }
+
+    /// Enter text mode.
+    pub fn run_text_mode<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&PageTextMode) -> Result<()>,
+    {
+        self.begin_text()?;
+        let page = PageTextMode::new(self.page);
+        let ret = f(&page);
+        self.end_text()?;
+
+        ret
+    }
+    /// Concatenate a translation to the current transformation matrix.
+    pub fn translate(&self, tx: Real, ty: Real) -> Result<()> {
+        self.page.concat(Matrix::translate(tx, ty))
+    }
+
+    /// Concatenate a counterclockwise rotation by `angle` degrees to the current transformation matrix.
+    pub fn rotate(&self, angle: Real) -> Result<()> {
+        self.page.concat(Matrix::rotate(angle))
+    }
+
+    /// Concatenate a scaling to the current transformation matrix.
+    pub fn scale(&self, sx: Real, sy: Real) -> Result<()> {
+        self.page.concat(Matrix::scale(sx, sy))
+    }
+
+    /// Concatenate a skew by `alpha` and `beta` degrees to the current transformation matrix.
+    pub fn skew(&self, alpha: Real, beta: Real) -> Result<()> {
+        self.page.concat(Matrix::skew(alpha, beta))
+    }
+
+    /// Run the closure with the matrix concatenated to the current transformation matrix.
+    ///
+    /// The graphics state is saved before and restored after the closure,
+    /// so the transform does not outlive it.
+    pub fn with_transform<F>(&self, matrix: Matrix, f: F) -> Result<()>
+    where
+        F: FnOnce(&PageDescriptionMode) -> Result<()>,
+    {
+        self.with_saved_state(|page| {
+            page.concat(matrix)?;
+            f(page)
+        })
+    }
+
+    /// Save the current graphics state, returning a guard which restores it when dropped.
+    pub fn save_state(&self) -> Result<GStateGuard<'doc, 'page>> {
+        self.page.gsave()?;
+
+        Ok(GStateGuard {
+            page: PageDescriptionMode::new(self.page),
+        })
+    }
+
+    /// Run the closure between saving and restoring the current graphics state.
+    ///
+    /// The state is restored even if the closure returns an error, which is then
+    /// returned in preference to an error from restoring the state.
+    pub fn with_saved_state<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&PageDescriptionMode) -> Result<()>,
+    {
+        let guard = self.save_state()?;
+        let ret = f(&guard);
+
+        ret.and(guard.restore())
+    }
+
+    /// Enter path mode.
+    ///
+    /// The closure is expected to end the path with a painting operator.
+    /// A clipping path left unpainted is ended with `end_path`; any other
+    /// unpainted path is ended too, and reported as `ErrorKind::PageInvalidGMode`.
+    pub fn run_path_mode<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&PagePathMode) -> Result<()>,
+    {
+        let page = PagePathMode::new(self.page);
+        let ret = f(&page);
+
+        /* an error from the closure takes precedence over one from ending the path. */
+        let cleanup = match self.page.gmode() {
+            Ok(GMode::ClippingPath) => page.end_path(),
+            Ok(GMode::PathObject) => {
+                page.end_path()
+                    .and(Err(Error::new(ErrorKind::PageInvalidGMode, 0, None)))
+            }
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        };
+
+        ret.and(cleanup)
+    }
+}
+
+impl<'doc, 'page> Deref for PageDescriptionMode<'doc, 'page> {
+    type Target = Page<'doc>;
+    fn deref(&self) -> &Self::Target {
+        self.page
+    }
+}
+
+impl<'doc, 'page> PageDescTeextCommonFunction<'doc> for PageDescriptionMode<'doc, 'page> {
+    fn handle(&self) -> &Page {
+        self.page
+    }
+}
+
+impl<'doc, 'page> PageDescPathCommonFunction<'doc> for PageDescriptionMode<'doc, 'page> {
+    fn handle(&self) -> &Page {
+        self.page
+    }
+}
+
+/// Saved graphics state of a page, restored when the guard is dropped.
+///
+/// Created by `PageDescriptionMode::save_state`.
+pub struct GStateGuard<'doc, 'page> {
+    page: PageDescriptionMode<'doc, 'page>,
+}
+
+impl<'doc, 'page> GStateGuard<'doc, 'page> {
+    /// Restore the saved graphics state, reporting an error dropping the guard would ignore.
+    pub fn restore(self) -> Result<()> {
+        let page = self.page.page;
+        std::mem::forget(self);
+
+        page.grestore()
+    }
+}
+
+impl<'doc, 'page> Deref for GStateGuard<'doc, 'page> {
+    type Target = PageDescriptionMode<'doc, 'page>;
+    fn deref(&self) -> &Self::Target {
+        &self.page
+    }
+}
+
+impl<'doc, 'page> Drop for GStateGuard<'doc, 'page> {
+    fn drop(&mut self) {
+        let _ = self.page.grestore();
+    }
+}
+
+//--------------------------------------------------------------------------------------
+
+/// Page object in text mode.
+pub struct PageTextMode<'doc, 'page> {
+    page: &'page Page<'doc>,
+}
+
+impl<'doc, 'page> PageTextMode<'doc, 'page> {
+    pub(crate) fn new(page: &'page Page<'doc>) -> Self {
+        Self { page }
+    }
+
+    /// Move the current text position to the start of the next line with using specified offset values.
+    pub fn move_text_pos<T>(&self, pos: T) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        let status =
+            unsafe { libharu_sys::HPDF_Page_MoveTextPos(self.page.handle(), pos.x, pos.y) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Page_MoveTextPos", status));
+        }
+
+        Ok(())
+    }
+
+    /// Move the current text position to the start of the next line with using specified offset values.
+    pub fn move_text_pos2<T>(&self, pos: T) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        let status =
+            unsafe { libharu_sys::HPDF_Page_MoveTextPos2(self.page.handle(), pos.x, pos.y) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Page_MoveTextPos2", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set text affine transformation matrix.
+    #[allow(clippy::many_single_char_names)]
+    pub fn set_text_matrix(
+        &self,
+        a: Real,
+        b: Real,
+        c: Real,
+        d: Real,
+        x: Real,
+        y: Real,
+    ) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_Page_SetTextMatrix(self.page.handle(), a, b, c, d, x, y) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Page_SetTextMatrix", status));
+        }
+
+        Ok(())
+    }
+
+    /// Move the current text position to the start of the next line with using specified offset values.
+    pub fn move_to_next_line(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_MoveToNextLine(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Page_MoveToNextLine", status));
+        }
+
+        Ok(())
+    }
+
+    /// Print the text on the specified position.
+    pub fn text_out<T>(&self, pos: T, text: &str) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_TextOut(
+                self.page.handle(),
+                pos.x,
+                pos.y,
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_TextOut", status));
+        }
+
+        Ok(())
+    }
+
+    /// Print the text at the current position, switching fonts along the fallback chain.
+    ///
+    /// The runs are shown in the current font size, and the current font and size are
+    /// restored afterwards. Fails with `ErrorKind::PageFontNotFound` if no font is set.
+    pub fn show_text_with_fallback(&self, fallback: &FontFallback, text: &str) -> Result<()> {
+        self.with_fallback(fallback, text, |_, run| self.show_text(run))
+    }
+
+    /// Print the text on the specified position, switching fonts along the fallback chain.
+    ///
+    /// The runs are shown in the current font size, and the current font and size are
+    /// restored afterwards. Fails with `ErrorKind::PageFontNotFound` if no font is set.
+    pub fn text_out_with_fallback<T>(
+        &self,
+        pos: T,
+        fallback: &FontFallback,
+        text: &str,
+    ) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        self.with_fallback(fallback, text, |i, run| {
+            if i == 0 {
+                self.text_out(pos, run)
+            } else {
+                self.show_text(run)
+            }
+        })
+    }
+
+    fn with_fallback<F>(&self, fallback: &FontFallback, text: &str, mut f: F) -> Result<()>
+    where
+        F: FnMut(usize, &str) -> Result<()>,
+    {
+        let font = unsafe { libharu_sys::HPDF_Page_GetCurrentFont(self.page.handle()) };
+        if font.is_null() {
+            return Err(Error::new(
+                ErrorKind::PageFontNotFound,
+                0,
+                Some("HPDF_Page_GetCurrentFont"),
+            ));
+        }
+        let font = Font::new(self.page.doc(), font);
+        let size = self.page.current_font_size()?;
+
+        let ret = fallback.runs(text).and_then(|runs| {
+            for (i, (run_font, run)) in runs.into_iter().enumerate() {
+                self.set_font_and_size(run_font, size)?;
+                f(i, run)?;
+            }
+            Ok(())
+        });
+
+        /* an error from a run takes precedence over one from restoring the font. */
+        ret.and(self.set_font_and_size(&font, size))
+    }
+
+    /// Print the text on the specified position. (bytes data)
+    pub fn text_out_bytes<T>(&self, pos: T, text: &[u8]) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+        let text = CString::new(text)?;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_TextOut(
+                self.page.handle(),
+                pos.x,
+                pos.y,
+                std::mem::transmute(text.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_TextOut", status));
+        }
+
+        Ok(())
+    }
+
+    /// Print the text inside the specified region.
+    ///
+    /// Fails with `ErrorKind::PageInsufficientSpace` if the text does not fit; the part which
+    /// fits is printed nonetheless.
+    pub fn text_rect<T>(&self, rect: T, text: &str, align: TextAlignment) -> Result<()>
+    where
+        T: Into<Rect>,
+    {
+        let rect = rect.into();
+        let text = CString::new(text)?;
+        let align = match align {
+            TextAlignment::Left => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_LEFT,
+            TextAlignment::Right => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_RIGHT,
+            TextAlignment::Center => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_CENTER,
+            TextAlignment::Justify => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_JUSTIFY,
+        };
+        let mut len = 0;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_TextRect(
+                self.page.handle(),
+                rect.left,
+                rect.top,
+                rect.right,
+                rect.bottom,
+                std::mem::transmute(text.as_ptr()),
+                align,
+                &mut len,
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_TextRect", status));
+        }
+
+        Ok(())
+    }
+
+    /// Print the text inside the specified region. (byte data)
+    ///
+    /// Fails with `ErrorKind::PageInsufficientSpace` if the text does not fit, like `text_rect`.
+    pub fn text_rect_bytes<T>(&self, rect: T, text: &[u8], align: TextAlignment) -> Result<()>
+    where
+        T: Into<Rect>,
+    {
+        let rect = rect.into();
+        let text = CString::new(text)?;
+        let align = match align {
+            TextAlignment::Left => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_LEFT,
+            TextAlignment::Right => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_RIGHT,
+            TextAlignment::Center => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_CENTER,
+            TextAlignment::Justify => libharu_sys::HPDF_TextAlignment::HPDF_TALIGN_JUSTIFY,
+        };
+        let mut len = 0;
+        let status = unsafe {
+            libharu_sys::HPDF_Page_TextRect(
+                self.page.handle(),
+                rect.left,
+                rect.top,
+                rect.right,
+                rect.bottom,
+                std::mem::transmute(text.as_ptr()),
+                align,
+                &mut len,
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_TextRect", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'doc, 'page> Deref for PageTextMode<'doc, 'page> {
+    type Target = Page<'doc>;
+    fn deref(&self) -> &Self::Target {
+        self.page
+    }
+}
+
+impl<'doc, 'page> PageDescTeextCommonFunction<'doc> for PageTextMode<'doc, 'page> {
+    fn handle(&self) -> &Page {
+        self.page
+    }
+}
+
+//-------------------------------------------------------------------------------------------
+
+/// Page object in Path mode.
+pub struct PagePathMode<'doc, 'page> {
+    page: &'page Page<'doc>,
+}
+
+impl<'doc, 'page> PagePathMode<'doc, 'page> {
+    pub(crate) fn new(page: &'page Page<'doc>) -> Self {
+        Self { page }
+    }
+
+    /// Start a new subpath and move the current point for drawing path,
+    pub fn move_to<T>(&self, pos: T) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        let status = unsafe { libharu_sys::HPDF_Page_MoveTo(self.page.handle(), pos.x, pos.y) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_MoveTo", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a Bézier curve to the current path using three spesified points.
+    pub fn curve_to<T1, T2, T3>(&self, point1: T1, point2: T2, point3: T3) -> Result<()>
+    where
+        T1: Into<Point>,
+        T2: Into<Point>,
+        T3: Into<Point>,
+    {
+        let point1 = point1.into();
+        let point2 = point2.into();
+        let point3 = point3.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_CurveTo(
+                self.page.handle(),
+                point1.x,
+                point1.y,
+                point2.x,
+                point2.y,
+                point3.x,
+                point3.y,
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_CurveTo", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a Bézier curve to the current path using two spesified points.
+    pub fn curve_to_2<T1, T2>(&self, point2: T1, point3: T2) -> Result<()>
+    where
+        T1: Into<Point>,
+        T2: Into<Point>,
+    {
+        let point2 = point2.into();
+        let point3 = point3.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_CurveTo2(
+                self.page.handle(),
+                point2.x,
+                point2.y,
+                point3.x,
+                point3.y,
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_CurveTo2", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a Bézier curve to the current path using two spesified points.
+    pub fn curve_to_3<T1, T2>(&self, point1: T1, point3: T2) -> Result<()>
+    where
+        T1: Into<Point>,
+        T2: Into<Point>,
+    {
+        let point1 = point1.into();
+        let point3 = point3.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_CurveTo3(
+                self.page.handle(),
+                point1.x,
+                point1.y,
+                point3.x,
+                point3.y,
+            )
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_CurveTo3", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a path from the current point to the specified point.
+    pub fn line_to<T>(&self, pos: T) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+        let status = unsafe { libharu_sys::HPDF_Page_LineTo(self.page.handle(), pos.x, pos.y) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_LineTo", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a rectangle to the current path.
+    pub fn rectangle<T>(&self, pos: T, width: Real, height: Real) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_Rectangle(self.page.handle(), pos.x, pos.y, width, height)
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_Rectangle", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a circle to the current path.
+    pub fn circle<T>(&self, pos: T, ray: Real) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        let status =
+            unsafe { libharu_sys::HPDF_Page_Circle(self.page.handle(), pos.x, pos.y, ray) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_Circle", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append a arc to the current path.
+    pub fn arc<T>(&self, pos: T, ray: Real, ang1: Real, ang2: Real) -> Result<()>
+    where
+        T: Into<Point>,
+    {
+        let pos = pos.into();
+
+        let status = unsafe {
+            libharu_sys::HPDF_Page_Arc(self.page.handle(), pos.x, pos.y, ray, ang1, ang2)
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_Arc", status));
+        }
+
+        Ok(())
+    }
+
+    /// Append the path described by SVG path data (the `d` attribute of `<path>`).
+    ///
+    /// All commands (M, L, H, V, C, S, Q, T, A and Z, absolute and relative) are supported;
+    /// quadratic Béziers and arcs are converted to cubic Béziers. Every point is mapped by
+    /// `transform`, e.g. `Matrix::flip_y(top)` to place SVG's y-down origin at the top of the page.
+    /// Nothing is appended if the data is invalid, and the error is
+    /// `ErrorKind::InvalidSvgPath` with the byte offset of the problem.
+    pub fn svg_path(&self, d: &str, transform: Matrix) -> Result<()> {
+        self.segments(&svg_path::parse(d)?, transform)
+    }
+
+    /* append already parsed SVG path data. */
+    pub(crate) fn segments(&self, segments: &[Segment], transform: Matrix) -> Result<()> {
+        for &segment in segments {
+            match segment {
+                Segment::MoveTo(p) => self.move_to(transform.transform_point(p))?,
+                Segment::LineTo(p) => self.line_to(transform.transform_point(p))?,
+                Segment::CurveTo(c1, c2, p) => self.curve_to(
+                    transform.transform_point(c1),
+                    transform.transform_point(c2),
+                    transform.transform_point(p),
+                )?,
+                Segment::ClosePath => self.close_path()?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Close the current subpath by appending a line to its start point.
+    pub fn close_path(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_ClosePath(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_ClosePath", status));
+        }
+
+        Ok(())
+    }
+
+    /// Paint the current path.
+    pub fn stroke(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_Stroke(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_Stroke", status));
+        }
+
+        Ok(())
+    }
+
+    /// Close the current subpath, then paint the current path.
+    pub fn close_path_stroke(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_ClosePathStroke(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self
+                .page
+                .doc()
+                .status_error("HPDF_Page_ClosePathStroke", status));
+        }
+
+        Ok(())
+    }
+
+    /// Fill the current path using the given fill rule.
+    pub fn fill(&self, rule: FillRule) -> Result<()> {
+        let (status, function) = match rule {
+            FillRule::NonZero => (
+                unsafe { libharu_sys::HPDF_Page_Fill(self.page.handle()) },
+                "HPDF_Page_Fill",
+            ),
+            FillRule::EvenOdd => (
+                unsafe { libharu_sys::HPDF_Page_Eofill(self.page.handle()) },
+                "HPDF_Page_Eofill",
+            ),
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error(function, status));
+        }
+
+        Ok(())
+    }
+
+    /// Fill the current path using the given fill rule, then paint the current path.
+    pub fn fill_stroke(&self, rule: FillRule) -> Result<()> {
+        let (status, function) = match rule {
+            FillRule::NonZero => (
+                unsafe { libharu_sys::HPDF_Page_FillStroke(self.page.handle()) },
+                "HPDF_Page_FillStroke",
+            ),
+            FillRule::EvenOdd => (
+                unsafe { libharu_sys::HPDF_Page_EofillStroke(self.page.handle()) },
+                "HPDF_Page_EofillStroke",
+            ),
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error(function, status));
+        }
+
+        Ok(())
+    }
+
+    /// Close the current subpath, fill the current path using the given fill rule,
+    /// then paint the current path.
+    pub fn close_path_fill_stroke(&self, rule: FillRule) -> Result<()> {
+        let (status, function) = match rule {
+            FillRule::NonZero => (
+                unsafe { libharu_sys::HPDF_Page_ClosePathFillStroke(self.page.handle()) },
+                "HPDF_Page_ClosePathFillStroke",
+            ),
+            FillRule::EvenOdd => (
+                unsafe { libharu_sys::HPDF_Page_ClosePathEofillStroke(self.page.handle()) },
+                "HPDF_Page_ClosePathEofillStroke",
+            ),
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error(function, status));
+        }
+
+        Ok(())
+    }
+
+    /// End the current path without painting it.
+    ///
+    /// Used after `clip` to set the clipping region without drawing the path.
+    pub fn end_path(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_Page_EndPath(self.page.handle()) };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Page_EndPath", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the clipping region to the intersection with the current path using the given fill rule.
+    ///
+    /// Takes effect once the path is ended by a painting operator or `end_path`.
+    pub fn clip(&self, rule: FillRule) -> Result<()> {
+        let (status, function) = match rule {
+            FillRule::NonZero => (
+                unsafe { libharu_sys::HPDF_Page_Clip(self.page.handle()) },
+                "HPDF_Page_Clip",
+            ),
+            FillRule::EvenOdd => (
+                unsafe { libharu_sys::HPDF_Page_Eoclip(self.page.handle()) },
+                "HPDF_Page_Eoclip",
+            ),
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error(function, status));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'doc, 'page> Deref for PagePathMode<'doc, 'page> {
+    type Target = Page<'doc>;
+    fn deref(&self) -> &Self::Target {
+        self.page
+    }
+}
+
+impl<'doc, 'page> PageDescPathCommonFunction<'doc> for PagePathMode<'doc, 'page> {
+    fn handle(&self) -> &Page {
+        self.page
+    }
+}
@@ -1,31 +1,143 @@
-use crate::page::Page;
-use crate::Real;
-
-/// Destination handle type.
-pub struct Destination<'a, 'b> {
-    dst: libharu_sys::HPDF_Destination,
-    _page: &'a Page<'b>,
-}
-
-impl<'a, 'b> Destination<'a, 'b> {
-    pub(crate) fn new(_page: &'a Page<'b>, dst: libharu_sys::HPDF_Destination) -> Self {
-        Self { dst, _page }
-    }
-    
-    #[inline]
-    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Destination {
-        self.dst
-    }
-
-    /// Define the appearance of a page with three parameters which are left, top and zoom.
-    pub fn set_xyz(&self, left: Real, top: Real, zoom: Real) -> anyhow::Result<()> {
-        let status = unsafe {
-            libharu_sys::HPDF_Destination_SetXYZ(self.handle(), left, top, zoom)
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Destination_SetXYZ failed (status={})", status);
-        }
-        Ok(())
-    }
-}
\ No newline at end of file
+use crate::page::Page;
+use crate::{Real, Result};
+
+/// Appearance of the page displayed by a destination.
+#[derive(Debug, Clone, Copy)]
+pub enum DestinationView {
+    /// Display the page with the given left-top position and zoom factor.
+    XYZ {
+        /// Left position
+        left: Real,
+
+        /// Top position
+        top: Real,
+
+        /// Zoom factor
+        zoom: Real,
+    },
+
+    /// Fit the entire page within the window.
+    Fit,
+
+    /// Fit the entire width of the page within the window, with the top at the given position.
+    FitH {
+        /// Top position
+        top: Real,
+    },
+
+    /// Fit the entire height of the page within the window, with the left at the given position.
+    FitV {
+        /// Left position
+        left: Real,
+    },
+
+    /// Fit the given rectangle of the page within the window.
+    FitR {
+        /// Left position
+        left: Real,
+
+        /// Bottom position
+        bottom: Real,
+
+        /// Right position
+        right: Real,
+
+        /// Top position
+        top: Real,
+    },
+
+    /// Fit the bounding box of the page within the window.
+    FitB,
+
+    /// Fit the width of the bounding box within the window, with the top at the given position.
+    FitBH {
+        /// Top position
+        top: Real,
+    },
+
+    /// Fit the height of the bounding box within the window, with the left at the given position.
+    FitBV {
+        /// Left position
+        left: Real,
+    },
+}
+
+/// Destination handle type.
+pub struct Destination<'a, 'b> {
+    dst: libharu_sys::HPDF_Destination,
+    page: &'a Page<'b>,
+}
+
+impl<'a, 'b> Destination<'a, 'b> {
+    pub(crate) fn new(page: &'a Page<'b>, dst: libharu_sys::HPDF_Destination) -> Self {
+        Self { dst, page }
+    }
+    
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Destination {
+        self.dst
+    }
+
+    /// Define the appearance of a page with three parameters which are left, top and zoom.
+    pub fn set_xyz(&self, left: Real, top: Real, zoom: Real) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_Destination_SetXYZ(self.handle(), left, top, zoom)
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error("HPDF_Destination_SetXYZ", status));
+        }
+        Ok(())
+    }
+
+    /// Define the appearance of the page displayed by the destination.
+    pub fn set_view(&self, view: DestinationView) -> Result<()> {
+        let dst = self.handle();
+        let (function, status) = unsafe {
+            match view {
+                DestinationView::XYZ { left, top, zoom } => (
+                    "HPDF_Destination_SetXYZ",
+                    libharu_sys::HPDF_Destination_SetXYZ(dst, left, top, zoom),
+                ),
+                DestinationView::Fit => (
+                    "HPDF_Destination_SetFit",
+                    libharu_sys::HPDF_Destination_SetFit(dst),
+                ),
+                DestinationView::FitH { top } => (
+                    "HPDF_Destination_SetFitH",
+                    libharu_sys::HPDF_Destination_SetFitH(dst, top),
+                ),
+                DestinationView::FitV { left } => (
+                    "HPDF_Destination_SetFitV",
+                    libharu_sys::HPDF_Destination_SetFitV(dst, left),
+                ),
+                DestinationView::FitR {
+                    left,
+                    bottom,
+                    right,
+                    top,
+                } => (
+                    "HPDF_Destination_SetFitR",
+                    libharu_sys::HPDF_Destination_SetFitR(dst, left, bottom, right, top),
+                ),
+                DestinationView::FitB => (
+                    "HPDF_Destination_SetFitB",
+                    libharu_sys::HPDF_Destination_SetFitB(dst),
+                ),
+                DestinationView::FitBH { top } => (
+                    "HPDF_Destination_SetFitBH",
+                    libharu_sys::HPDF_Destination_SetFitBH(dst, top),
+                ),
+                DestinationView::FitBV { left } => (
+                    "HPDF_Destination_SetFitBV",
+                    libharu_sys::HPDF_Destination_SetFitBV(dst, left),
+                ),
+            }
+        };
+
+        if status != 0 {
+            return Err(self.page.doc().status_error(function, status));
+        }
+        Ok(())
+    }
+}
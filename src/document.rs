@@ -1,820 +1,1420 @@
-#![warn(missing_docs)]
-
-use crate::destination::Destination;
-use crate::encoder::Encoder;
-use crate::error::Error;
-use crate::image::Image;
-use crate::outline::Outline;
-use crate::page::Page;
-use crate::Font;
-
-use bitflags::bitflags;
-use std::convert::TryInto;
-use std::ffi::{c_uchar, CString};
-
-/// Page label style.
-#[derive(Debug)]
-pub enum PageNumStyle {
-    /// Page label is displayed by Arabic numerals.
-    Decimal,
-
-    /// Page label is displayed by Uppercase roman numerals.
-    UpperRoman,
-
-    /// Page label is displayed by Lowercase roman numerals.
-    LowerRoman,
-
-    /// Page label is displayed by Uppercase letters (using A to Z).
-    UpperLetters,
-
-    /// Page label is displayed by Lowercase letters (using a to Z).
-    LowerLetters,
-}
-
-bitflags! {
-    /// The flags specifying which type of contents should be compressed.
-    pub struct CompressionMode: u32 {
-        /// All contents are not compressed.
-        const NONE     = 0x00;
-
-        /// Compress the contents stream of the page.
-        const TEXT     = 0x01;
-
-        /// Compress the streams of the image objects.
-        const IMAGE    = 0x02;
-
-        /// Other stream datas (fonts, cmaps and so on) are compressed.
-        const METADATA = 0x04;
-
-        /// All stream datas are compressed. (The same as `CompressionMode::Text | CompressionMode::Image | CompressionMode::Metadata`)
-        const ALL = Self::TEXT.bits() | Self::IMAGE.bits() | Self::METADATA.bits();
-    }
-}
-
-/// Page display style.
-#[derive(Debug)]
-pub enum PageMode {
-    /// Display the document with neither outline nor thumbnail.
-    None,
-
-    /// Display the document with outline pain.
-    Outline,
-
-    /// Display the document with thumbnail pain.
-    Thumbs,
-
-    /// Display the document with full screen mode.
-    FullScreen,
-}
-
-/// Page layout style.
-#[derive(Debug)]
-pub enum PageLayout {
-    /// Only one page is displayed.
-    Single,
-
-    /// Display the pages in one column.
-    OneColumn,
-
-    /// Display the pages in two column. The page of the odd number is displayed left.
-    TwoColumnLeft,
-
-    /// Display the pages in two column. The page of the odd number is displayed right.
-    TwoColumnRight,
-}
-
-#[derive(Debug)]
-/// PDF Metadata
-pub enum Metadata {
-    /// Creation date of the document
-    CreationDate,
-
-    /// Last modified date
-    ModDate,
-
-    /// Document author
-    Author,
-
-    /// Document creator
-    Creator,
-
-    /// Document producer (default: Libharu)
-    Producer,
-
-    /// Document title
-    Title,
-
-    /// Document subject
-    Subject,
-
-    /// Document keywords
-    Keywords,
-    Trapped,
-
-    /// PDF Document version
-    PDFXVersion,
-}
-// onerrorのクロージャをBoxで持ちたいためInnerを別にしている。
-// TODO: onerrorは必要か？
-struct DocumentInner {
-    onerror: Box<dyn Fn(Error)>,
-    last_errno: libharu_sys::HPDF_STATUS,
-    last_detailno: libharu_sys::HPDF_STATUS,
-}
-
-/// PDF Document handle type.
-///
-/// The document handle is a handle to operate a document object.
-pub struct Document {
-    doc: libharu_sys::HPDF_Doc,
-
-    #[allow(dead_code)]
-    inner: Box<DocumentInner>,
-}
-
-pub struct PageBuilder<'a> {
-    page: Page<'a>,
-}
-
-impl <'a> PageBuilder <'a> {
-    pub fn width(&mut self, width: f32) -> &mut PageBuilder<'a> {
-        self.page.set_width(width).unwrap();
-        self
-    }
-    pub fn height(&mut self, height: f32) -> &mut PageBuilder<'a> {
-        self.page.set_height(height).unwrap();
-        self
-    }
-    pub fn rotate(&mut self, rotation: u16) -> &mut PageBuilder<'a> {
-        self.page.set_rotate(rotation).unwrap();
-        self
-    }
-    pub fn build(self) -> Page<'a> {
-        self.page
-    }
-}
-
-impl Document {
-    /// Create a new instance of document.
-    pub fn new(onerror: impl Fn(Error) + 'static) -> anyhow::Result<Self> {
-        let onerror = Box::new(onerror);
-        let mut inner = Box::new(DocumentInner {
-            onerror,
-            last_errno: 0,
-            last_detailno: 0,
-        });
-
-        let doc =
-            unsafe { libharu_sys::HPDF_New(onerror_callback, std::mem::transmute(inner.as_mut())) };
-
-        if doc.is_null() {
-            anyhow::bail!("HPDF_New() failed");
-        }
-
-        Ok(Self { doc, inner })
-    }
-    //Im wondering about adding a build page function
-    // pub fn build_page(&self) -> PageBuilder {
-    //     let ptr = unsafe { libharu_sys::HPDF_AddPage(self.handle()) };
-    //     let page = Page::new(self, ptr);
-    //     PageBuilder { page }
-    // }
-    #[inline]
-    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Doc {
-        self.doc
-    }
-
-    /// Create a new page and adds it after the last page of a document.
-    pub fn add_page(&self) -> anyhow::Result<Page> {
-        let page = unsafe { libharu_sys::HPDF_AddPage(self.handle()) };
-
-        if page.is_null() {
-            anyhow::bail!("HPDF_AddPage failed");
-        }
-
-        Ok(Page::new(self, page))
-    }
-
-    /// Return the current page object.
-    pub fn current_page(&self) -> anyhow::Result<Page> {
-        let page = unsafe { libharu_sys::HPDF_GetCurrentPage(self.handle()) };
-
-        if page.is_null() {
-            anyhow::bail!("HPDF_GetCurrentPage failed");
-        }
-
-        Ok(Page::new(self, page))
-    }
-
-    /// Set how the document should be displayed.
-    pub fn set_page_mode(&self, mode: PageMode) -> anyhow::Result<()> {
-        let mode = match mode {
-            PageMode::None => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_NONE,
-            PageMode::Outline => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_OUTLINE,
-            PageMode::Thumbs => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_THUMBS,
-            PageMode::FullScreen => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_FULL_SCREEN,
-        };
-
-        let status = unsafe { libharu_sys::HPDF_SetPageMode(self.handle(), mode) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetPageMode failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Get how the document should be displayed.
-    pub fn page_mode(&self) -> anyhow::Result<PageMode> {
-        let mode = unsafe { libharu_sys::HPDF_GetPageMode(self.handle()) };
-
-        let mode = match mode {
-            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_NONE => PageMode::None,
-            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_OUTLINE => PageMode::Outline,
-            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_THUMBS => PageMode::Thumbs,
-            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_FULL_SCREEN => PageMode::FullScreen,
-            _ => {
-                anyhow::bail!("HPDF_GetPageMode failed");
-            }
-        };
-
-        Ok(mode)
-    }
-
-    /// Create a new page and inserts it just before the specified page.
-    pub fn insert_page(&self, target: &Page) -> anyhow::Result<Page> {
-        let page = unsafe { libharu_sys::HPDF_InsertPage(self.handle(), target.handle()) };
-
-        if page.is_null() {
-            anyhow::bail!("HPDF_InsertPage failed");
-        }
-
-        Ok(Page::new(self, page))
-    }
-
-    /// Gets the handle of a corresponding font object by specified name and encoding.
-    pub fn font(&self, font_name: &str, encoding_name: Option<&str>) -> anyhow::Result<Font> {
-        let font_name = CString::new(font_name)?;
-        let encoding_name = match encoding_name {
-            Some(s) => Some(CString::new(s)?),
-            None => None,
-        };
-
-        let font = unsafe {
-            libharu_sys::HPDF_GetFont(
-                self.handle(),
-                std::mem::transmute(font_name.as_ptr()),
-                match encoding_name {
-                    Some(ref s) => std::mem::transmute(s.as_ptr()),
-                    None => std::ptr::null_mut(),
-                },
-            )
-        };
-
-        if font.is_null() {
-            anyhow::bail!("HPDF_GetFont failed");
-        }
-
-        Ok(Font::new(self, font))
-    }
-
-    /// Add a page labeling range for the document.
-    pub fn add_page_label(
-        &self,
-        page_num: usize,
-        style: PageNumStyle,
-        first_page: usize,
-        prefix: Option<&str>,
-    ) -> anyhow::Result<()> {
-        let style = match style {
-            PageNumStyle::Decimal => libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_DECIMAL,
-            PageNumStyle::UpperRoman => {
-                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_UPPER_ROMAN
-            }
-            PageNumStyle::LowerRoman => {
-                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_LOWER_ROMAN
-            }
-            PageNumStyle::UpperLetters => {
-                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_UPPER_LETTERS
-            }
-            PageNumStyle::LowerLetters => {
-                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_LOWER_LETTERS
-            }
-        };
-
-        let page_num = page_num.try_into()?;
-        let first_page = first_page.try_into()?;
-
-        let prefix = match prefix {
-            Some(s) => CString::new(s)?,
-            None => CString::new("")?,
-        };
-        let status = unsafe {
-            libharu_sys::HPDF_AddPageLabel(
-                self.handle(),
-                page_num,
-                style,
-                first_page,
-                std::mem::transmute(prefix.as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_AddPageLabelf failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable Japanese fonts. After the method invoked, an application can use the following Japanese fonts.
-    /// * MS-mincho
-    /// * MS-mincho,Bold
-    /// * MS-mincho,Bold
-    /// * MS-mincho,Italic
-    /// * MS-mincho,BoldItalic
-    /// * MS-Gothic
-    /// * MS-Gothic,Bold
-    /// * MS-Gothic,Italic
-    /// * MS-Gothic,BoldItalic
-    /// * MS-Pmincho
-    /// * MS-Pmincho,Bold
-    /// * MS-Pmincho,Italic
-    /// * MS-Pmincho,BoldItalic
-    /// * MS-PGothic
-    /// * MS-PGothic,Bold
-    /// * MS-PGothic,Italic
-    /// * MS-PGothic,BoldItalic
-    pub fn use_jpfonts(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseJPFonts(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseJPFonts failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable Korian fonts. After the method invoked, an application can use the following Korean fonts.
-    /// * DotumChe
-    /// * DotumChe,Bold
-    /// * DotumChe,Italic
-    /// * DotumChe,BoldItalic
-    /// * Dotum
-    /// * Dotum,Bold
-    /// * Dotum,Italic
-    /// * Dotum,BoldItalic
-    /// * BatangChe
-    /// * BatangChe,Bold
-    /// * BatangChe,Italic
-    /// * BatangChe,BoldItalic
-    /// * Batang
-    /// * Batang,Bold
-    /// * Batang,Italic
-    /// * Batang,BoldItalic
-    pub fn use_krfonts(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseKRFonts(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseKRFonts failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable simplified Chinese fonts. After the method invoked, an application can use the following simplified Chinese fonts.
-    /// * SimSun
-    /// * SimSun,Bold
-    /// * SimSun,Italic
-    /// * SimSun,BoldItalic
-    /// * SimHei
-    /// * SimHei,Bold
-    /// * SimHei,Italic
-    /// * SimHei,BoldItalic
-    pub fn use_cnsfonts(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseCNSFonts(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseCNSFonts failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable traditional Chinese fonts. After the method invoked, an application can use the following traditional Chinese fonts.
-    /// * MingLiU
-    /// * MingLiU,Bold
-    /// * MingLiU,Italic
-    /// * MingLiU,BoldItalic
-    pub fn use_cntfonts(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseCNTFonts(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseCNTFonts failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable Japanese encodings. After the method invoked, an application can use the following Japanese encodings.
-    /// * 90ms-RKSJ-H
-    /// * 90ms-RKSJ-V
-    /// * 90msp-RKSJ-H
-    /// * EUC-H
-    /// * EUC-V
-    pub fn use_jpencodings(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseJPEncodings(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseJPEncodings failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable Korean encodings. After the method is invoked, an application can use the following Korean encodings.
-    /// * KSC-EUC-H
-    /// * KSC-EUC-V
-    /// * KSCms-UHC-H
-    /// * KSCms-UHC-HW-H
-    /// * KSCms-UHC-HW-V
-    pub fn use_krencodings(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseKREncodings(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseKREncodings failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable simplified Chinese encodings. After the method is invoked, an application can use the following simplified Chinese encodings.
-    /// * GB-EUC-H
-    /// * GB-EUC-V
-    /// * GBK-EUC-H
-    /// * GBK-EUC-V
-    pub fn use_cnsencodings(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseCNSEncodings(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseCNSEncodings failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable traditional Chinese encodings. After the method is invoked, an application can use the following traditional Chinese encodings.
-    /// * GB-EUC-H
-    /// * GB-EUC-V
-    /// * GBK-EUC-H
-    /// * GBK-EUC-V
-    pub fn use_cntencodings(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseCNTEncodings(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseCNTEncodings failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Enable UTF-8 encoding.
-    pub fn use_utfencodings(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_UseUTFEncodings(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_UseCNTEncodings failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Save the current document to a file.
-    pub fn save_to_file(&self, name: &str) -> anyhow::Result<()> {
-        let name = CString::new(name).unwrap();
-        let status = unsafe {
-            libharu_sys::HPDF_SaveToFile(
-                self.handle(),
-                std::mem::transmute(name.as_bytes().as_ptr()),
-            )
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SaveToFile failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Save the current document to stream
-    pub fn save_to_stream(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_SaveToStream(self.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SaveToFile failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Get the size of the stream
-    pub fn get_stream_size(&self) -> anyhow::Result<u32> {
-        let size = unsafe { libharu_sys::HPDF_GetStreamSize(self.handle()) };
-
-        Ok(size)
-    }
-    pub fn reset_stream(&self) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_ResetStream(self.handle()) };
-        if status != 0 {
-            anyhow::bail!("HPDF_ResetStream failed (status = {}", status);
-        }
-        Ok(())
-    }
-    /// Read the pdf file from memory and saves it to the buffer. Requires a size, which can be gotten from get_stream_size
-    pub fn read_from_stream(&self, buf: &mut [u8], size: u32) -> anyhow::Result<()> {
-        let box_u32 = Box::into_raw(Box::new(size));
-        let data = buf.as_mut_ptr();
-        let status = unsafe { libharu_sys::HPDF_ReadFromStream(self.handle(), data, box_u32) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetCompressionMode failed (status = {})", status);
-        }
-        Ok(())
-    }
-    pub fn get_contents(&self, buf: &mut [u8], size: u32) -> anyhow::Result<()> {
-        let box_u32 = Box::into_raw(Box::new(size));
-        let data = buf.as_mut_ptr();
-        let status = unsafe { libharu_sys::HPDF_GetContents(self.handle(), data, box_u32) };
-        if status != 0 {
-            anyhow::bail!("HPDF_SetCompressionMode failed (status = {}", status);
-        }
-        Ok(())
-    }
-    /// Set the mode of compression.
-    pub fn set_compression_mode(&self, mode: CompressionMode) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_SetCompressionMode(self.handle(), mode.bits()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetCompressionMode failed (status = {})", status);
-        }
-
-        Ok(())
-    }
-
-    /// creates root outline object.
-    pub fn create_outline(
-        &self,
-        title: &str,
-        parent: Option<&Outline>,
-        enc: Option<&Encoder>,
-    ) -> anyhow::Result<Outline> {
-        let title = CString::new(title)?;
-
-        let outline = unsafe {
-            libharu_sys::HPDF_CreateOutline(
-                self.handle(),
-                match parent {
-                    Some(p) => p.handle(),
-                    None => std::ptr::null_mut(),
-                },
-                title.as_ptr() as *const i8,
-                match enc {
-                    Some(e) => e.handle(),
-                    None => std::ptr::null_mut(),
-                },
-            )
-        };
-
-        if outline.is_null() {
-            anyhow::bail!("HPDF_CreateOutline failed");
-        }
-
-        Ok(Outline::new(self, outline))
-    }
-
-    /// creates root outline object. (raw bytes)
-    pub fn create_outline_bytes(
-        &self,
-        title: &[u8],
-        parent: Option<&Outline>,
-        enc: Option<&Encoder>,
-    ) -> anyhow::Result<Outline> {
-        let title = CString::new(title)?;
-
-        let outline = unsafe {
-            libharu_sys::HPDF_CreateOutline(
-                self.handle(),
-                match parent {
-                    Some(p) => p.handle(),
-                    None => std::ptr::null_mut(),
-                },
-                title.as_ptr() as *const i8,
-                match enc {
-                    Some(e) => e.handle(),
-                    None => std::ptr::null_mut(),
-                },
-            )
-        };
-
-        if outline.is_null() {
-            anyhow::bail!("HPDF_CreateOutline failed");
-        }
-
-        Ok(Outline::new(self, outline))
-    }
-
-    /// Get the handle of a corresponding encoder object by specified encoding name.
-    pub fn find_encoder(&self, encoding_name: &str) -> anyhow::Result<Encoder> {
-        let encoding_name = CString::new(encoding_name)?;
-        let enc = unsafe { libharu_sys::HPDF_GetEncoder(self.handle(), encoding_name.as_ptr()) };
-
-        if enc.is_null() {
-            anyhow::bail!("HPDF_GetEncoder failed");
-        }
-
-        Ok(Encoder::new(self, enc))
-    }
-
-    /// Get the handle of the current encoder of the document object.
-    pub fn current_encoder(&self) -> anyhow::Result<Encoder> {
-        let enc = unsafe { libharu_sys::HPDF_GetCurrentEncoder(self.handle()) };
-
-        if enc.is_null() {
-            anyhow::bail!("HPDF_GetCurrentEncoder failed");
-        }
-
-        Ok(Encoder::new(self, enc))
-    }
-
-    /// Set the handle of the current encoder of the document object.
-    pub fn set_current_encoder(&self, encoding_name: &str) -> anyhow::Result<()> {
-        let encoding_name = CString::new(encoding_name)?;
-        let status =
-            unsafe { libharu_sys::HPDF_SetCurrentEncoder(self.handle(), encoding_name.as_ptr()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetCurrentEncoder failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// Set file attributes of the document object
-    pub fn set_file_attr(&self, attr: Metadata, value: &str) -> anyhow::Result<()> {
-        let attr_value = CString::new(value)?;
-
-        let info_type = match attr {
-            Metadata::CreationDate => libharu_sys::HPDF_InfoType::HPDF_INFO_CREATION_DATE,
-            Metadata::ModDate => libharu_sys::HPDF_InfoType::HPDF_INFO_MOD_DATE,
-            Metadata::Author => libharu_sys::HPDF_InfoType::HPDF_INFO_AUTHOR,
-            Metadata::Creator => libharu_sys::HPDF_InfoType::HPDF_INFO_CREATOR,
-            Metadata::Producer => libharu_sys::HPDF_InfoType::HPDF_INFO_PRODUCER,
-            Metadata::Title => libharu_sys::HPDF_InfoType::HPDF_INFO_TITLE,
-            Metadata::Subject => libharu_sys::HPDF_InfoType::HPDF_INFO_SUBJECT,
-            Metadata::Keywords => libharu_sys::HPDF_InfoType::HPDF_INFO_KEYWORDS,
-            Metadata::Trapped => libharu_sys::HPDF_InfoType::HPDF_INFO_TRAPPED,
-            Metadata::PDFXVersion => libharu_sys::HPDF_InfoType::HPDF_INFO_GTS_PDFX,
-        };
-
-        let status =
-            unsafe { libharu_sys::HPDF_SetInfoAttr(self.handle(), info_type, attr_value.as_ptr()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetInfoAttr failed (status={}", status);
-        }
-        Ok(())
-    }
-
-    /// Get the current setting for page layout.
-    pub fn page_layout(&self) -> anyhow::Result<PageLayout> {
-        let layout = unsafe { libharu_sys::HPDF_GetPageLayout(self.handle()) };
-
-        Ok(match layout {
-            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_SINGLE => PageLayout::Single,
-            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_ONE_COLUMN => PageLayout::OneColumn,
-            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_LEFT => {
-                PageLayout::TwoColumnLeft
-            }
-            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_RIGHT => {
-                PageLayout::TwoColumnRight
-            }
-            _ => anyhow::bail!("HPDF_GetPageLayout failed"),
-        })
-    }
-
-    /// Set how the page should be displayed. If this attribute is not set, the setting of a viewer application is used.
-    pub fn set_page_layout(&self, layout: PageLayout) -> anyhow::Result<()> {
-        let layout = match layout {
-            PageLayout::Single => libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_SINGLE,
-            PageLayout::OneColumn => libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_ONE_COLUMN,
-            PageLayout::TwoColumnLeft => {
-                libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_LEFT
-            }
-            PageLayout::TwoColumnRight => {
-                libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_RIGHT
-            }
-        };
-
-        let status = unsafe { libharu_sys::HPDF_SetPageLayout(self.handle(), layout) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetPageLayout failed (status={})", status);
-        }
-
-        Ok(())
-    }
-
-    /// load a TrueType font from an external file and register it to a document object.
-    pub fn load_ttf_font(&self, name: &str, embedding: bool) -> anyhow::Result<&str> {
-        let name = CString::new(name)?;
-        let ret = unsafe {
-            libharu_sys::HPDF_LoadTTFontFromFile(
-                self.handle(),
-                name.as_ptr(),
-                if embedding { 1 } else { 0 },
-            )
-        };
-
-        if ret.is_null() {
-            anyhow::bail!("HPDF_LoadTTFontFromFile failed");
-        }
-
-        let s = unsafe { std::ffi::CStr::from_ptr(ret).to_str()? };
-
-        //let ret = unsafe { CString::from_raw(ret as *mut i8).into_string()? };
-        Ok(s)
-    }
-
-    /// Load a TrueType font from an TrueType collection file and register it to a document object.
-    pub fn load_ttf_font_from_ttc(
-        &self,
-        name: &str,
-        index: usize,
-        embedding: bool,
-    ) -> anyhow::Result<&str> {
-        let name = CString::new(name)?;
-        let index = index as u32;
-
-        let ret = unsafe {
-            libharu_sys::HPDF_LoadTTFontFromFile2(
-                self.handle(),
-                name.as_ptr(),
-                index,
-                if embedding { 1 } else { 0 },
-            )
-        };
-
-        if ret.is_null() {
-            anyhow::bail!("HPDF_LoadTTFontFromFile failed");
-        }
-
-        let s = unsafe { std::ffi::CStr::from_ptr(ret).to_str()? };
-
-        //let ret = unsafe { CString::from_raw(ret as *mut i8).into_string()? };
-        Ok(s)
-    }
-
-    /// Load an external png image file.
-    pub fn load_png_image(&self, name: &str) -> anyhow::Result<Image> {
-        let name = CString::new(name)?;
-
-        let image = unsafe { libharu_sys::HPDF_LoadPngImageFromFile(self.handle(), name.as_ptr()) };
-
-        if image.is_null() {
-            anyhow::bail!("HPDF_LoadPngImageFromFile failed");
-        }
-
-        Ok(Image::new(self, image))
-    }
-
-    /// Set the first page appears when a document is opened.
-    pub fn set_open_action(&self, dst: &Destination) -> anyhow::Result<()> {
-        let status = unsafe { libharu_sys::HPDF_SetOpenAction(self.handle(), dst.handle()) };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_SetOpenAction failed (status={})", status);
-        }
-
-        Ok(())
-    }
-}
-
-impl Drop for Document {
-    fn drop(&mut self) {
-        unsafe {
-            libharu_sys::HPDF_Free(self.handle());
-        }
-    }
-}
-
-extern "C" fn onerror_callback(
-    errno: libharu_sys::HPDF_STATUS,
-    detailno: libharu_sys::HPDF_STATUS,
-    userdata: libharu_sys::HPDF_HANDLE,
-) {
-    #[allow(clippy::transmute_ptr_to_ref)]
-    let inner: &mut DocumentInner = unsafe { std::mem::transmute(userdata) };
-    inner.last_errno = errno;
-    inner.last_detailno = detailno;
-
-    (inner.onerror)(Error::from_num(errno));
-}
+#![warn(missing_docs)]
+
+use crate::destination::Destination;
+use crate::encoder::Encoder;
+use crate::error::{Error, ErrorKind};
+use crate::ext_gstate::ExtGState;
+use crate::image::{ColorSpace, Image};
+use crate::outline::{Outline, OutlineBuilder};
+use crate::page::Page;
+use crate::shading::{Gradient, Shading};
+use crate::stream::DocumentReader;
+use crate::{Font, Rect, Result};
+
+use bitflags::bitflags;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::RandomState;
+use std::convert::TryInto;
+use std::ffi::{c_uchar, CStr, CString};
+use std::fs::OpenOptions;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Page label style.
+#[derive(Debug)]
+pub enum PageNumStyle {
+    /// Page label is displayed by Arabic numerals.
+    Decimal,
+
+    /// Page label is displayed by Uppercase roman numerals.
+    UpperRoman,
+
+    /// Page label is displayed by Lowercase roman numerals.
+    LowerRoman,
+
+    /// Page label is displayed by Uppercase letters (using A to Z).
+    UpperLetters,
+
+    /// Page label is displayed by Lowercase letters (using a to Z).
+    LowerLetters,
+}
+
+bitflags! {
+    /// The flags specifying which type of contents should be compressed.
+    pub struct CompressionMode: u32 {
+        /// All contents are not compressed.
+        const NONE     = 0x00;
+
+        /// Compress the contents stream of the page.
+        const TEXT     = 0x01;
+
+        /// Compress the streams of the image objects.
+        const IMAGE    = 0x02;
+
+        /// Other stream datas (fonts, cmaps and so on) are compressed.
+        const METADATA = 0x04;
+
+        /// All stream datas are compressed. (The same as `CompressionMode::Text | CompressionMode::Image | CompressionMode::Metadata`)
+        const ALL = Self::TEXT.bits() | Self::IMAGE.bits() | Self::METADATA.bits();
+    }
+}
+
+bitflags! {
+    /// The flags specifying which operations are permitted on an encrypted document.
+    ///
+    /// libharu always grants the permissions not listed here (filling forms, extracting text
+    /// for accessibility, assembling and high-quality printing).
+    pub struct Permissions: u32 {
+        /// The document can only be read.
+        const READ     = 0x00;
+
+        /// The document can be printed.
+        const PRINT    = 0x04;
+
+        /// The document can be edited except for annotations and form fields.
+        const EDIT_ALL = 0x08;
+
+        /// The text and graphics of the document can be copied.
+        const COPY     = 0x10;
+
+        /// Annotations and form fields can be added or modified.
+        const EDIT     = 0x20;
+
+        /// All of the above. (The same as `Permissions::PRINT | Permissions::EDIT_ALL | Permissions::COPY | Permissions::EDIT`)
+        const ALL = Self::PRINT.bits() | Self::EDIT_ALL.bits() | Self::COPY.bits() | Self::EDIT.bits();
+    }
+}
+
+/// Encryption algorithm of the document.
+#[derive(Debug, Clone, Copy)]
+pub enum EncryptionMode {
+    /// RC4 with a 40 bit key (PDF 1.3, revision 2). The key length must be 5 bytes.
+    Rc4_40,
+
+    /// RC4 with a key of up to 128 bits (PDF 1.4, revision 3). The key length must be 5 to 16 bytes.
+    Rc4_128,
+}
+
+/// Page display style.
+#[derive(Debug)]
+pub enum PageMode {
+    /// Display the document with neither outline nor thumbnail.
+    None,
+
+    /// Display the document with outline pain.
+    Outline,
+
+    /// Display the document with thumbnail pain.
+    Thumbs,
+
+    /// Display the document with full screen mode.
+    FullScreen,
+}
+
+/// Page layout style.
+#[derive(Debug)]
+pub enum PageLayout {
+    /// Only one page is displayed.
+    Single,
+
+    /// Display the pages in one column.
+    OneColumn,
+
+    /// Display the pages in two column. The page of the odd number is displayed left.
+    TwoColumnLeft,
+
+    /// Display the pages in two column. The page of the odd number is displayed right.
+    TwoColumnRight,
+}
+
+#[derive(Debug)]
+/// PDF Metadata
+pub enum Metadata {
+    /// Creation date of the document
+    CreationDate,
+
+    /// Last modified date
+    ModDate,
+
+    /// Document author
+    Author,
+
+    /// Document creator
+    Creator,
+
+    /// Document producer (default: Libharu)
+    Producer,
+
+    /// Document title
+    Title,
+
+    /// Document subject
+    Subject,
+
+    /// Document keywords
+    Keywords,
+    Trapped,
+
+    /// PDF Document version
+    PDFXVersion,
+}
+// onerrorのクロージャをBoxで持ちたいためInnerを別にしている。
+// TODO: onerrorは必要か？
+struct DocumentInner {
+    onerror: Box<dyn Fn(ErrorKind)>,
+    last_errno: Cell<libharu_sys::HPDF_STATUS>,
+    last_detailno: Cell<libharu_sys::HPDF_STATUS>,
+    utf_encodings: Cell<bool>,
+
+    /* set while reading the stream, whose end libharu reports as an error. */
+    reading_stream: Cell<bool>,
+
+    /* font files libharu keeps reading from until the document is freed. */
+    font_files: RefCell<Vec<TempFile>>,
+
+    /* every page created, checked for unrestored graphics states at save time. */
+    pages: RefCell<Vec<libharu_sys::HPDF_Page>>,
+}
+
+/// PDF Document handle type.
+///
+/// The document handle is a handle to operate a document object.
+pub struct Document {
+    doc: libharu_sys::HPDF_Doc,
+
+    #[allow(dead_code)]
+    inner: Box<DocumentInner>,
+}
+
+pub struct PageBuilder<'a> {
+    page: Page<'a>,
+}
+
+impl <'a> PageBuilder <'a> {
+    pub fn width(&mut self, width: f32) -> &mut PageBuilder<'a> {
+        self.page.set_width(width).unwrap();
+        self
+    }
+    pub fn height(&mut self, height: f32) -> &mut PageBuilder<'a> {
+        self.page.set_height(height).unwrap();
+        self
+    }
+    pub fn rotate(&mut self, rotation: u16) -> &mut PageBuilder<'a> {
+        self.page.set_rotate(rotation).unwrap();
+        self
+    }
+    pub fn build(self) -> Page<'a> {
+        self.page
+    }
+}
+
+impl Document {
+    /// Create a new instance of document.
+    pub fn new(onerror: impl Fn(ErrorKind) + 'static) -> Result<Self> {
+        let onerror = Box::new(onerror);
+        let mut inner = Box::new(DocumentInner {
+            onerror,
+            last_errno: Cell::new(0),
+            last_detailno: Cell::new(0),
+            utf_encodings: Cell::new(false),
+            reading_stream: Cell::new(false),
+            font_files: RefCell::new(Vec::new()),
+            pages: RefCell::new(Vec::new()),
+        });
+
+        let doc =
+            unsafe { libharu_sys::HPDF_New(onerror_callback, std::mem::transmute(inner.as_mut())) };
+
+        if doc.is_null() {
+            return Err(Error::new(ErrorKind::FailedToAllocMem, 0, Some("HPDF_New")));
+        }
+
+        Ok(Self { doc, inner })
+    }
+    //Im wondering about adding a build page function
+    // pub fn build_page(&self) -> PageBuilder {
+    //     let ptr = unsafe { libharu_sys::HPDF_AddPage(self.handle()) };
+    //     let page = Page::new(self, ptr);
+    //     PageBuilder { page }
+    // }
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Doc {
+        self.doc
+    }
+
+    /// Build an error for a libharu function which returned a status code.
+    pub(crate) fn status_error(
+        &self,
+        function: &'static str,
+        status: libharu_sys::HPDF_STATUS,
+    ) -> Error {
+        Error::new(
+            ErrorKind::from_num(status),
+            self.inner.last_detailno.get(),
+            Some(function),
+        )
+    }
+
+    /// Build an error for a libharu function which signalled failure without a status code
+    /// (e.g. by returning a null handle), using the last error reported to the error handler.
+    pub(crate) fn last_error(&self, function: &'static str) -> Error {
+        Error::new(
+            ErrorKind::from_num(self.inner.last_errno.get()),
+            self.inner.last_detailno.get(),
+            Some(function),
+        )
+    }
+
+    /// Forget the last error reported to the error handler, before calling a libharu function
+    /// whose failure is then read with `last_error`.
+    pub(crate) fn reset_last_error(&self) {
+        self.inner.last_errno.set(0);
+        self.inner.last_detailno.set(0);
+    }
+
+    /// Create a new page and adds it after the last page of a document.
+    pub fn add_page(&self) -> Result<Page> {
+        self.reset_last_error();
+        let page = unsafe { libharu_sys::HPDF_AddPage(self.handle()) };
+
+        if page.is_null() {
+            return Err(self.last_error("HPDF_AddPage"));
+        }
+        self.inner.pages.borrow_mut().push(page);
+
+        Ok(Page::new(self, page))
+    }
+
+    /// Return the current page object.
+    pub fn current_page(&self) -> Result<Page> {
+        self.reset_last_error();
+        let page = unsafe { libharu_sys::HPDF_GetCurrentPage(self.handle()) };
+
+        if page.is_null() {
+            return Err(self.last_error("HPDF_GetCurrentPage"));
+        }
+
+        Ok(Page::new(self, page))
+    }
+
+    /// Set how the document should be displayed.
+    pub fn set_page_mode(&self, mode: PageMode) -> Result<()> {
+        let mode = match mode {
+            PageMode::None => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_NONE,
+            PageMode::Outline => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_OUTLINE,
+            PageMode::Thumbs => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_THUMBS,
+            PageMode::FullScreen => libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_FULL_SCREEN,
+        };
+
+        let status = unsafe { libharu_sys::HPDF_SetPageMode(self.handle(), mode) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetPageMode", status));
+        }
+
+        Ok(())
+    }
+
+    /// Get how the document should be displayed.
+    pub fn page_mode(&self) -> Result<PageMode> {
+        self.reset_last_error();
+        let mode = unsafe { libharu_sys::HPDF_GetPageMode(self.handle()) };
+
+        let mode = match mode {
+            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_NONE => PageMode::None,
+            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_OUTLINE => PageMode::Outline,
+            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_USE_THUMBS => PageMode::Thumbs,
+            libharu_sys::HPDF_PageMode::HPDF_PAGE_MODE_FULL_SCREEN => PageMode::FullScreen,
+            _ => {
+                return Err(self.last_error("HPDF_GetPageMode"));
+            }
+        };
+
+        Ok(mode)
+    }
+
+    /// Create a new page and inserts it just before the specified page.
+    pub fn insert_page(&self, target: &Page) -> Result<Page> {
+        self.reset_last_error();
+        let page = unsafe { libharu_sys::HPDF_InsertPage(self.handle(), target.handle()) };
+
+        if page.is_null() {
+            return Err(self.last_error("HPDF_InsertPage"));
+        }
+        self.inner.pages.borrow_mut().push(page);
+
+        Ok(Page::new(self, page))
+    }
+
+    /// Gets the handle of a corresponding font object by specified name and encoding.
+    pub fn font(&self, font_name: &str, encoding_name: Option<&str>) -> Result<Font> {
+        let font_name = CString::new(font_name)?;
+        let encoding_name = match encoding_name {
+            Some(s) => Some(CString::new(s)?),
+            None => None,
+        };
+
+        self.reset_last_error();
+        let font = unsafe {
+            libharu_sys::HPDF_GetFont(
+                self.handle(),
+                std::mem::transmute(font_name.as_ptr()),
+                match encoding_name {
+                    Some(ref s) => std::mem::transmute(s.as_ptr()),
+                    None => std::ptr::null_mut(),
+                },
+            )
+        };
+
+        if font.is_null() {
+            return Err(self.last_error("HPDF_GetFont"));
+        }
+
+        Ok(Font::new(self, font))
+    }
+
+    /// Add a page labeling range for the document.
+    pub fn add_page_label(
+        &self,
+        page_num: usize,
+        style: PageNumStyle,
+        first_page: usize,
+        prefix: Option<&str>,
+    ) -> Result<()> {
+        let style = match style {
+            PageNumStyle::Decimal => libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_DECIMAL,
+            PageNumStyle::UpperRoman => {
+                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_UPPER_ROMAN
+            }
+            PageNumStyle::LowerRoman => {
+                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_LOWER_ROMAN
+            }
+            PageNumStyle::UpperLetters => {
+                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_UPPER_LETTERS
+            }
+            PageNumStyle::LowerLetters => {
+                libharu_sys::HPDF_PageNumStyle::HPDF_PAGE_NUM_STYLE_LOWER_LETTERS
+            }
+        };
+
+        let page_num = page_num.try_into()?;
+        let first_page = first_page.try_into()?;
+
+        let prefix = match prefix {
+            Some(s) => CString::new(s)?,
+            None => CString::new("")?,
+        };
+        let status = unsafe {
+            libharu_sys::HPDF_AddPageLabel(
+                self.handle(),
+                page_num,
+                style,
+                first_page,
+                std::mem::transmute(prefix.as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_AddPageLabel", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable Japanese fonts. After the method invoked, an application can use the following Japanese fonts.
+    /// * MS-mincho
+    /// * MS-mincho,Bold
+    /// * MS-mincho,Bold
+    /// * MS-mincho,Italic
+    /// * MS-mincho,BoldItalic
+    /// * MS-Gothic
+    /// * MS-Gothic,Bold
+    /// * MS-Gothic,Italic
+    /// * MS-Gothic,BoldItalic
+    /// * MS-Pmincho
+    /// * MS-Pmincho,Bold
+    /// * MS-Pmincho,Italic
+    /// * MS-Pmincho,BoldItalic
+    /// * MS-PGothic
+    /// * MS-PGothic,Bold
+    /// * MS-PGothic,Italic
+    /// * MS-PGothic,BoldItalic
+    pub fn use_jpfonts(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseJPFonts(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseJPFonts", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable Korian fonts. After the method invoked, an application can use the following Korean fonts.
+    /// * DotumChe
+    /// * DotumChe,Bold
+    /// * DotumChe,Italic
+    /// * DotumChe,BoldItalic
+    /// * Dotum
+    /// * Dotum,Bold
+    /// * Dotum,Italic
+    /// * Dotum,BoldItalic
+    /// * BatangChe
+    /// * BatangChe,Bold
+    /// * BatangChe,Italic
+    /// * BatangChe,BoldItalic
+    /// * Batang
+    /// * Batang,Bold
+    /// * Batang,Italic
+    /// * Batang,BoldItalic
+    pub fn use_krfonts(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseKRFonts(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseKRFonts", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable simplified Chinese fonts. After the method invoked, an application can use the following simplified Chinese fonts.
+    /// * SimSun
+    /// * SimSun,Bold
+    /// * SimSun,Italic
+    /// * SimSun,BoldItalic
+    /// * SimHei
+    /// * SimHei,Bold
+    /// * SimHei,Italic
+    /// * SimHei,BoldItalic
+    pub fn use_cnsfonts(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseCNSFonts(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseCNSFonts", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable traditional Chinese fonts. After the method invoked, an application can use the following traditional Chinese fonts.
+    /// * MingLiU
+    /// * MingLiU,Bold
+    /// * MingLiU,Italic
+    /// * MingLiU,BoldItalic
+    pub fn use_cntfonts(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseCNTFonts(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseCNTFonts", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable Japanese encodings. After the method invoked, an application can use the following Japanese encodings.
+    /// * 90ms-RKSJ-H
+    /// * 90ms-RKSJ-V
+    /// * 90msp-RKSJ-H
+    /// * EUC-H
+    /// * EUC-V
+    pub fn use_jpencodings(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseJPEncodings(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseJPEncodings", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable Korean encodings. After the method is invoked, an application can use the following Korean encodings.
+    /// * KSC-EUC-H
+    /// * KSC-EUC-V
+    /// * KSCms-UHC-H
+    /// * KSCms-UHC-HW-H
+    /// * KSCms-UHC-HW-V
+    pub fn use_krencodings(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseKREncodings(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseKREncodings", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable simplified Chinese encodings. After the method is invoked, an application can use the following simplified Chinese encodings.
+    /// * GB-EUC-H
+    /// * GB-EUC-V
+    /// * GBK-EUC-H
+    /// * GBK-EUC-V
+    pub fn use_cnsencodings(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseCNSEncodings(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseCNSEncodings", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable traditional Chinese encodings. After the method is invoked, an application can use the following traditional Chinese encodings.
+    /// * GB-EUC-H
+    /// * GB-EUC-V
+    /// * GBK-EUC-H
+    /// * GBK-EUC-V
+    pub fn use_cntencodings(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseCNTEncodings(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseCNTEncodings", status));
+        }
+
+        Ok(())
+    }
+
+    /// Enable UTF-8 encoding.
+    pub fn use_utfencodings(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_UseUTFEncodings(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_UseUTFEncodings", status));
+        }
+
+        self.inner.utf_encodings.set(true);
+
+        Ok(())
+    }
+
+    /// Save the current document to a file.
+    pub fn save_to_file(&self, name: &str) -> Result<()> {
+        self.debug_assert_gstate_restored();
+
+        let name = CString::new(name)?;
+        let status = unsafe {
+            libharu_sys::HPDF_SaveToFile(
+                self.handle(),
+                std::mem::transmute(name.as_bytes().as_ptr()),
+            )
+        };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SaveToFile", status));
+        }
+
+        Ok(())
+    }
+
+    /* every gsave on a page should have been matched by a grestore before saving. */
+    fn debug_assert_gstate_restored(&self) {
+        if cfg!(debug_assertions) {
+            for &page in self.inner.pages.borrow().iter() {
+                let depth = unsafe { libharu_sys::HPDF_Page_GetGStateDepth(page) };
+                debug_assert!(
+                    depth <= 1,
+                    "page saved with {} unrestored graphics state(s)",
+                    depth - 1
+                );
+            }
+        }
+    }
+
+    /// Save the current document to stream
+    pub fn save_to_stream(&self) -> Result<()> {
+        self.debug_assert_gstate_restored();
+
+        let status = unsafe { libharu_sys::HPDF_SaveToStream(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SaveToStream", status));
+        }
+
+        Ok(())
+    }
+
+    /// Get the size of the stream
+    pub fn get_stream_size(&self) -> Result<u32> {
+        let size = unsafe { libharu_sys::HPDF_GetStreamSize(self.handle()) };
+
+        Ok(size)
+    }
+
+    /// Rewind the stream so that it can be read again from the beginning.
+    pub fn reset_stream(&self) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_ResetStream(self.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_ResetStream", status));
+        }
+
+        Ok(())
+    }
+
+    /// Read the next chunk of the stream written by `save_to_stream` into the buffer.
+    ///
+    /// Returns the number of bytes read, which is 0 once the end of the stream is reached.
+    /// Reaching the end is not reported to the error handler.
+    pub fn read_from_stream(&self, buf: &mut [u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut size = buf.len().min(u32::MAX as usize) as u32;
+        self.inner.reading_stream.set(true);
+        let status =
+            unsafe { libharu_sys::HPDF_ReadFromStream(self.handle(), buf.as_mut_ptr(), &mut size) };
+        self.inner.reading_stream.set(false);
+
+        if status != 0 {
+            if ErrorKind::from_num(status) != ErrorKind::StreamEof {
+                return Err(self.status_error("HPDF_ReadFromStream", status));
+            }
+
+            /* reaching the end of the stream is not an error for the caller. */
+            unsafe { libharu_sys::HPDF_ResetError(self.handle()) };
+        }
+
+        Ok(size as usize)
+    }
+
+    /// Save the document into the buffer.
+    ///
+    /// Returns the number of bytes written. The buffer must be at least `get_stream_size` bytes long.
+    pub fn get_contents(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut size = buf.len().min(u32::MAX as usize) as u32;
+        let status =
+            unsafe { libharu_sys::HPDF_GetContents(self.handle(), buf.as_mut_ptr(), &mut size) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_GetContents", status));
+        }
+
+        Ok(size as usize)
+    }
+
+    /// Save the document to the stream and return a reader over its contents.
+    ///
+    /// The stream is rewound when the reader is dropped.
+    pub fn reader(&self) -> Result<DocumentReader> {
+        self.save_to_stream()?;
+        let size = self.get_stream_size()?;
+
+        Ok(DocumentReader::new(self, size))
+    }
+
+    /// Save the document and write it to `writer` in chunks.
+    ///
+    /// Returns the number of bytes written.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<u64> {
+        let mut reader = self.reader()?;
+        let written = std::io::copy(&mut reader, &mut writer)?;
+
+        Ok(written)
+    }
+
+    /// Save the document and return it as bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut reader = self.reader()?;
+        let mut buf = Vec::with_capacity(reader.len());
+        reader.read_to_end(&mut buf)?;
+
+        Ok(buf)
+    }
+
+    /// Set the mode of compression.
+    pub fn set_compression_mode(&self, mode: CompressionMode) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_SetCompressionMode(self.handle(), mode.bits()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetCompressionMode", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the owner and user passwords and enable encryption of the document.
+    ///
+    /// The owner password must be non-empty and differ from the user password.
+    pub fn set_password(&self, owner_password: &str, user_password: &str) -> Result<()> {
+        let owner_password = CString::new(owner_password)?;
+        let user_password = CString::new(user_password)?;
+
+        let status = unsafe {
+            libharu_sys::HPDF_SetPassword(
+                self.handle(),
+                owner_password.as_ptr(),
+                user_password.as_ptr(),
+            )
+        };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetPassword", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the operations permitted to users of the encrypted document.
+    ///
+    /// `set_password` must be called first.
+    pub fn set_permission(&self, permission: Permissions) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_SetPermission(self.handle(), permission.bits()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetPermission", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the encryption algorithm and key length (in bytes) of the document.
+    ///
+    /// `set_password` must be called first.
+    pub fn set_encryption_mode(&self, mode: EncryptionMode, key_len: usize) -> Result<()> {
+        let valid = match mode {
+            EncryptionMode::Rc4_40 => key_len == 5,
+            EncryptionMode::Rc4_128 => (5..=16).contains(&key_len),
+        };
+
+        if !valid {
+            return Err(Error::new(
+                ErrorKind::InvalidEncryptKeyLen,
+                0,
+                Some("HPDF_SetEncryptionMode"),
+            ));
+        }
+
+        let mode = match mode {
+            EncryptionMode::Rc4_40 => libharu_sys::HPDF_EncryptMode::HPDF_ENCRYPT_R2,
+            EncryptionMode::Rc4_128 => libharu_sys::HPDF_EncryptMode::HPDF_ENCRYPT_R3,
+        };
+
+        let status =
+            unsafe { libharu_sys::HPDF_SetEncryptionMode(self.handle(), mode, key_len as u32) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetEncryptionMode", status));
+        }
+
+        Ok(())
+    }
+
+    /// creates root outline object.
+    pub fn create_outline(
+        &self,
+        title: &str,
+        parent: Option<&Outline>,
+        enc: Option<&Encoder>,
+    ) -> Result<Outline> {
+        let title = CString::new(title)?;
+
+        self.reset_last_error();
+        let outline = unsafe {
+            libharu_sys::HPDF_CreateOutline(
+                self.handle(),
+                match parent {
+                    Some(p) => p.handle(),
+                    None => std::ptr::null_mut(),
+                },
+                title.as_ptr() as *const i8,
+                match enc {
+                    Some(e) => e.handle(),
+                    None => std::ptr::null_mut(),
+                },
+            )
+        };
+
+        if outline.is_null() {
+            return Err(self.last_error("HPDF_CreateOutline"));
+        }
+
+        Ok(Outline::new(self, outline))
+    }
+
+    /// Get a builder of the outline tree of the document.
+    pub fn outline_builder(&self) -> OutlineBuilder {
+        OutlineBuilder::new(self)
+    }
+
+    /// creates root outline object. (raw bytes)
+    pub fn create_outline_bytes(
+        &self,
+        title: &[u8],
+        parent: Option<&Outline>,
+        enc: Option<&Encoder>,
+    ) -> Result<Outline> {
+        let title = CString::new(title)?;
+
+        self.reset_last_error();
+        let outline = unsafe {
+            libharu_sys::HPDF_CreateOutline(
+                self.handle(),
+                match parent {
+                    Some(p) => p.handle(),
+                    None => std::ptr::null_mut(),
+                },
+                title.as_ptr() as *const i8,
+                match enc {
+                    Some(e) => e.handle(),
+                    None => std::ptr::null_mut(),
+                },
+            )
+        };
+
+        if outline.is_null() {
+            return Err(self.last_error("HPDF_CreateOutline"));
+        }
+
+        Ok(Outline::new(self, outline))
+    }
+
+    /// Get the handle of a corresponding encoder object by specified encoding name.
+    pub fn find_encoder(&self, encoding_name: &str) -> Result<Encoder> {
+        let encoding_name = CString::new(encoding_name)?;
+        self.reset_last_error();
+        let enc = unsafe { libharu_sys::HPDF_GetEncoder(self.handle(), encoding_name.as_ptr()) };
+
+        if enc.is_null() {
+            return Err(self.last_error("HPDF_GetEncoder"));
+        }
+
+        Ok(Encoder::new(self, enc))
+    }
+
+    /// Get the handle of the current encoder of the document object.
+    pub fn current_encoder(&self) -> Result<Encoder> {
+        self.reset_last_error();
+        let enc = unsafe { libharu_sys::HPDF_GetCurrentEncoder(self.handle()) };
+
+        if enc.is_null() {
+            return Err(self.last_error("HPDF_GetCurrentEncoder"));
+        }
+
+        Ok(Encoder::new(self, enc))
+    }
+
+    /// Set the handle of the current encoder of the document object.
+    pub fn set_current_encoder(&self, encoding_name: &str) -> Result<()> {
+        let encoding_name = CString::new(encoding_name)?;
+        let status =
+            unsafe { libharu_sys::HPDF_SetCurrentEncoder(self.handle(), encoding_name.as_ptr()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetCurrentEncoder", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set file attributes of the document object
+    pub fn set_file_attr(&self, attr: Metadata, value: &str) -> Result<()> {
+        let attr_value = CString::new(value)?;
+
+        let info_type = match attr {
+            Metadata::CreationDate => libharu_sys::HPDF_InfoType::HPDF_INFO_CREATION_DATE,
+            Metadata::ModDate => libharu_sys::HPDF_InfoType::HPDF_INFO_MOD_DATE,
+            Metadata::Author => libharu_sys::HPDF_InfoType::HPDF_INFO_AUTHOR,
+            Metadata::Creator => libharu_sys::HPDF_InfoType::HPDF_INFO_CREATOR,
+            Metadata::Producer => libharu_sys::HPDF_InfoType::HPDF_INFO_PRODUCER,
+            Metadata::Title => libharu_sys::HPDF_InfoType::HPDF_INFO_TITLE,
+            Metadata::Subject => libharu_sys::HPDF_InfoType::HPDF_INFO_SUBJECT,
+            Metadata::Keywords => libharu_sys::HPDF_InfoType::HPDF_INFO_KEYWORDS,
+            Metadata::Trapped => libharu_sys::HPDF_InfoType::HPDF_INFO_TRAPPED,
+            Metadata::PDFXVersion => libharu_sys::HPDF_InfoType::HPDF_INFO_GTS_PDFX,
+        };
+
+        let status =
+            unsafe { libharu_sys::HPDF_SetInfoAttr(self.handle(), info_type, attr_value.as_ptr()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetInfoAttr", status));
+        }
+        Ok(())
+    }
+
+    /// Get the current setting for page layout.
+    pub fn page_layout(&self) -> Result<PageLayout> {
+        self.reset_last_error();
+        let layout = unsafe { libharu_sys::HPDF_GetPageLayout(self.handle()) };
+
+        Ok(match layout {
+            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_SINGLE => PageLayout::Single,
+            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_ONE_COLUMN => PageLayout::OneColumn,
+            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_LEFT => {
+                PageLayout::TwoColumnLeft
+            }
+            libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_RIGHT => {
+                PageLayout::TwoColumnRight
+            }
+            _ => return Err(self.last_error("HPDF_GetPageLayout")),
+        })
+    }
+
+    /// Set how the page should be displayed. If this attribute is not set, the setting of a viewer application is used.
+    pub fn set_page_layout(&self, layout: PageLayout) -> Result<()> {
+        let layout = match layout {
+            PageLayout::Single => libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_SINGLE,
+            PageLayout::OneColumn => libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_ONE_COLUMN,
+            PageLayout::TwoColumnLeft => {
+                libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_LEFT
+            }
+            PageLayout::TwoColumnRight => {
+                libharu_sys::HPDF_PageLayout::HPDF_PAGE_LAYOUT_TWO_COLUMN_RIGHT
+            }
+        };
+
+        let status = unsafe { libharu_sys::HPDF_SetPageLayout(self.handle(), layout) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetPageLayout", status));
+        }
+
+        Ok(())
+    }
+
+    /// load a TrueType font from an external file and register it to a document object.
+    ///
+    /// libharu always subsets an embedded font when the document is saved: only the glyphs
+    /// of the text shown or measured with the font are written, and the font name gets a
+    /// subset tag such as `HPDFAA+`. There is no option to embed the whole font.
+    pub fn load_ttf_font(&self, name: &str, embedding: bool) -> Result<String> {
+        let name = CString::new(name)?;
+        self.reset_last_error();
+        let ret = unsafe {
+            libharu_sys::HPDF_LoadTTFontFromFile(
+                self.handle(),
+                name.as_ptr(),
+                if embedding { 1 } else { 0 },
+            )
+        };
+
+        if ret.is_null() {
+            return Err(self.last_error("HPDF_LoadTTFontFromFile"));
+        }
+
+        let s = unsafe { CStr::from_ptr(ret).to_str()? };
+
+        Ok(s.to_owned())
+    }
+
+    /// Load a TrueType font from an TrueType collection file and register it to a document object.
+    pub fn load_ttf_font_from_ttc(
+        &self,
+        name: &str,
+        index: usize,
+        embedding: bool,
+    ) -> Result<String> {
+        let name = CString::new(name)?;
+        let index = index as u32;
+
+        self.reset_last_error();
+        let ret = unsafe {
+            libharu_sys::HPDF_LoadTTFontFromFile2(
+                self.handle(),
+                name.as_ptr(),
+                index,
+                if embedding { 1 } else { 0 },
+            )
+        };
+
+        if ret.is_null() {
+            return Err(self.last_error("HPDF_LoadTTFontFromFile2"));
+        }
+
+        let s = unsafe { CStr::from_ptr(ret).to_str()? };
+
+        Ok(s.to_owned())
+    }
+
+    /// Load a TrueType font from memory and get the font bound to an encoder.
+    ///
+    /// Without an encoding name, "UTF-8" is used if `use_utfencodings` was called.
+    ///
+    /// libharu only reads TrueType fonts from files, and reads the glyphs of an embedded font
+    /// from the still open file when the document is saved. The data is therefore written to a
+    /// randomly named file in the temporary directory, which takes that much disk space until
+    /// the `Document` is dropped. Failing to write it is reported as `ErrorKind::Io`.
+    pub fn load_ttf_font_from_bytes(
+        &self,
+        data: &[u8],
+        embedding: bool,
+        encoding_name: Option<&str>,
+    ) -> Result<Font> {
+        let file = TempFile::new(data, "ttf")?;
+        let name = self.load_ttf_font(file.path()?, embedding)?;
+        self.inner.font_files.borrow_mut().push(file);
+
+        self.font(&name, self.default_encoding(encoding_name))
+    }
+
+    /// Load a TrueType font from a TrueType collection in memory and get the font bound to an encoder.
+    ///
+    /// Without an encoding name, "UTF-8" is used if `use_utfencodings` was called.
+    /// The whole collection is written to a temporary file kept until the `Document` is
+    /// dropped, as in `load_ttf_font_from_bytes`.
+    pub fn load_ttf_font_from_ttc_bytes(
+        &self,
+        data: &[u8],
+        index: usize,
+        embedding: bool,
+        encoding_name: Option<&str>,
+    ) -> Result<Font> {
+        let file = TempFile::new(data, "ttc")?;
+        let name = self.load_ttf_font_from_ttc(file.path()?, index, embedding)?;
+        self.inner.font_files.borrow_mut().push(file);
+
+        self.font(&name, self.default_encoding(encoding_name))
+    }
+
+    /* get the "UTF-8" encoder, enabling UTF encodings if needed. */
+    pub(crate) fn utf8_encoder(&self) -> Result<Encoder> {
+        if !self.inner.utf_encodings.get() {
+            self.use_utfencodings()?;
+        }
+
+        self.find_encoder("UTF-8")
+    }
+
+    fn default_encoding<'b>(&self, encoding_name: Option<&'b str>) -> Option<&'b str> {
+        match encoding_name {
+            Some(s) => Some(s),
+            None if self.inner.utf_encodings.get() => Some("UTF-8"),
+            None => None,
+        }
+    }
+
+    /// Load a Type1 font from an external AFM file and optional PFB file and register it to a document object.
+    ///
+    /// Without a PFB file the font is not embedded. Returns the name of the font to use with `Document::font`.
+    pub fn load_type1_font(&self, afm: &str, pfb: Option<&str>) -> Result<String> {
+        let afm = CString::new(afm)?;
+        let pfb = match pfb {
+            Some(pfb) => Some(CString::new(pfb)?),
+            None => None,
+        };
+
+        self.reset_last_error();
+        let ret = unsafe {
+            libharu_sys::HPDF_LoadType1FontFromFile(
+                self.handle(),
+                afm.as_ptr(),
+                pfb.as_ref().map_or(std::ptr::null(), |pfb| pfb.as_ptr()),
+            )
+        };
+
+        if ret.is_null() {
+            return Err(self.last_error("HPDF_LoadType1FontFromFile"));
+        }
+
+        let s = unsafe { CStr::from_ptr(ret).to_str()? };
+
+        Ok(s.to_owned())
+    }
+
+    /// Load a Type1 font from AFM and optional PFB data in memory and register it to a document object.
+    ///
+    /// libharu only reads Type1 fonts from files, so the data is written to randomly named
+    /// files in the temporary directory. libharu copies the font while loading it, so the
+    /// files are removed before this returns. Failing to write them is reported as
+    /// `ErrorKind::Io`.
+    pub fn load_type1_font_from_mem(&self, afm: &[u8], pfb: Option<&[u8]>) -> Result<String> {
+        let afm = TempFile::new(afm, "afm")?;
+        let pfb = match pfb {
+            Some(pfb) => Some(TempFile::new(pfb, "pfb")?),
+            None => None,
+        };
+
+        self.load_type1_font(afm.path()?, pfb.as_ref().map(TempFile::path).transpose()?)
+    }
+
+    /// Load an external png image file.
+    pub fn load_png_image(&self, name: &str) -> Result<Image> {
+        let name = CString::new(name)?;
+
+        self.reset_last_error();
+        let image = unsafe { libharu_sys::HPDF_LoadPngImageFromFile(self.handle(), name.as_ptr()) };
+
+        if image.is_null() {
+            return Err(self.last_error("HPDF_LoadPngImageFromFile"));
+        }
+
+        Ok(Image::new(self, image))
+    }
+
+    /// Load an external png image file. The image data is not read until the document is saved.
+    pub fn load_png_image_deferred(&self, name: &str) -> Result<Image> {
+        let name = CString::new(name)?;
+
+        self.reset_last_error();
+        let image =
+            unsafe { libharu_sys::HPDF_LoadPngImageFromFile2(self.handle(), name.as_ptr()) };
+
+        if image.is_null() {
+            return Err(self.last_error("HPDF_LoadPngImageFromFile2"));
+        }
+
+        Ok(Image::new(self, image))
+    }
+
+    /// Load a png image from memory.
+    pub fn load_png_image_from_mem(&self, data: &[u8]) -> Result<Image> {
+        let size = data.len().try_into()?;
+
+        self.reset_last_error();
+        let image =
+            unsafe { libharu_sys::HPDF_LoadPngImageFromMem(self.handle(), data.as_ptr(), size) };
+
+        if image.is_null() {
+            return Err(self.last_error("HPDF_LoadPngImageFromMem"));
+        }
+
+        Ok(Image::new(self, image))
+    }
+
+    /// Load an external jpeg image file.
+    pub fn load_jpeg_image(&self, name: &str) -> Result<Image> {
+        let name = CString::new(name)?;
+
+        self.reset_last_error();
+        let image =
+            unsafe { libharu_sys::HPDF_LoadJpegImageFromFile(self.handle(), name.as_ptr()) };
+
+        if image.is_null() {
+            return Err(self.last_error("HPDF_LoadJpegImageFromFile"));
+        }
+
+        Ok(Image::new(self, image))
+    }
+
+    /// Load a jpeg image from memory.
+    pub fn load_jpeg_image_from_mem(&self, data: &[u8]) -> Result<Image> {
+        let size = data.len().try_into()?;
+
+        self.reset_last_error();
+        let image =
+            unsafe { libharu_sys::HPDF_LoadJpegImageFromMem(self.handle(), data.as_ptr(), size) };
+
+        if image.is_null() {
+            return Err(self.last_error("HPDF_LoadJpegImageFromMem"));
+        }
+
+        Ok(Image::new(self, image))
+    }
+
+    /// Load an image from uncompressed pixel data in memory.
+    ///
+    /// `color_space` must be `DeviceGray`, `DeviceRGB` or `DeviceCMYK` and
+    /// `bits_per_component` one of 1, 2, 4 or 8.
+    pub fn load_raw_image_from_mem(
+        &self,
+        data: &[u8],
+        width: u32,
+        height: u32,
+        color_space: ColorSpace,
+        bits_per_component: u32,
+    ) -> Result<Image> {
+        let components = match color_space {
+            ColorSpace::DeviceGray => 1,
+            ColorSpace::DeviceRGB => 3,
+            ColorSpace::DeviceCMYK => 4,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidColorSpace,
+                    0,
+                    Some("HPDF_LoadRawImageFromMem"),
+                ))
+            }
+        };
+
+        if ![1, 2, 4, 8].contains(&bits_per_component) {
+            return Err(Error::new(
+                ErrorKind::InvalidBitPerComponent,
+                0,
+                Some("HPDF_LoadRawImageFromMem"),
+            ));
+        }
+
+        /* libharu copies this many bytes from the buffer without checking its length. */
+        let pixels = width as f64 * height as f64 / (8 / bits_per_component) as f64;
+        let required = (pixels + 0.876) as usize * components;
+        if data.len() < required {
+            return Err(Error::new(
+                ErrorKind::InvalidParameter,
+                0,
+                Some("HPDF_LoadRawImageFromMem"),
+            ));
+        }
+
+        self.reset_last_error();
+        let image = unsafe {
+            libharu_sys::HPDF_LoadRawImageFromMem(
+                self.handle(),
+                data.as_ptr(),
+                width,
+                height,
+                color_space.into(),
+                bits_per_component,
+            )
+        };
+
+        if image.is_null() {
+            return Err(self.last_error("HPDF_LoadRawImageFromMem"));
+        }
+
+        Ok(Image::new(self, image))
+    }
+
+    /// Create a new extended graphics state object.
+    pub fn create_ext_gstate(&self) -> Result<ExtGState> {
+        self.reset_last_error();
+        let gstate = unsafe { libharu_sys::HPDF_CreateExtGState(self.handle()) };
+
+        if gstate.is_null() {
+            return Err(self.last_error("HPDF_CreateExtGState"));
+        }
+
+        Ok(ExtGState::new(self, gstate))
+    }
+
+    /// Create a shading painting the gradient over `bbox`.
+    ///
+    /// Without extension, only the part of `bbox` between the start and end of the gradient is painted.
+    /// The shading is in DeviceRGB, so CMYK stops are painted as converted by `Gradient::add_stop`.
+    pub fn create_shading<R>(&self, gradient: &Gradient, bbox: R) -> Result<Shading>
+    where
+        R: Into<Rect>,
+    {
+        let bbox = bbox.into().normalized();
+        let triangles = gradient.triangles(bbox)?;
+
+        /* vertex coordinates are encoded relative to this range. */
+        let mut range = bbox;
+        for &(p, _) in triangles.iter().flatten() {
+            range.left = range.left.min(p.x);
+            range.right = range.right.max(p.x);
+            range.bottom = range.bottom.min(p.y);
+            range.top = range.top.max(p.y);
+        }
+        if range.right <= range.left || range.top <= range.bottom {
+            return Err(Error::new(
+                ErrorKind::InvalidParameter,
+                0,
+                Some("HPDF_Shading_New"),
+            ));
+        }
+
+        self.reset_last_error();
+        let shading = unsafe {
+            libharu_sys::HPDF_Shading_New(
+                self.handle(),
+                libharu_sys::HPDF_ShadingType::HPDF_SHADING_FREE_FORM_TRIANGLE_MESH,
+                libharu_sys::HPDF_ColorSpace::HPDF_CS_DEVICE_RGB,
+                range.left,
+                range.right,
+                range.bottom,
+                range.top,
+            )
+        };
+
+        if shading.is_null() {
+            return Err(self.last_error("HPDF_Shading_New"));
+        }
+
+        let shading = Shading::new(self, shading);
+        for triangle in &triangles {
+            shading.add_triangle(triangle)?;
+        }
+
+        Ok(shading)
+    }
+
+    /// Set the first page appears when a document is opened.
+    pub fn set_open_action(&self, dst: &Destination) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_SetOpenAction(self.handle(), dst.handle()) };
+
+        if status != 0 {
+            return Err(self.status_error("HPDF_SetOpenAction", status));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Document {
+    fn drop(&mut self) {
+        unsafe {
+            libharu_sys::HPDF_Free(self.handle());
+        }
+    }
+}
+
+/// File holding in-memory data for libharu functions which only read from files.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn new(data: &[u8], extension: &str) -> Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        /* the name has a random part, and create_new refuses existing files and symlinks. */
+        let (path, mut file) = loop {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_usize(COUNTER.fetch_add(1, Ordering::Relaxed));
+            let path = std::env::temp_dir().join(format!(
+                "libharu-{}-{:016x}.{}",
+                std::process::id(),
+                hasher.finish(),
+                extension
+            ));
+
+            let mut options = OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+            match options.open(&path) {
+                Ok(file) => break (path, file),
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err.into()),
+            }
+        };
+
+        /* removed on drop if writing fails. */
+        let ret = Self { path };
+        file.write_all(data)?;
+
+        Ok(ret)
+    }
+
+    fn path(&self) -> Result<&str> {
+        match self.path.to_str() {
+            Some(path) => Ok(path),
+            None => Err(ErrorKind::InvalidParameter.into()),
+        }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+extern "C" fn onerror_callback(
+    errno: libharu_sys::HPDF_STATUS,
+    detailno: libharu_sys::HPDF_STATUS,
+    userdata: libharu_sys::HPDF_HANDLE,
+) {
+    #[allow(clippy::transmute_ptr_to_ref)]
+    let inner: &DocumentInner = unsafe { std::mem::transmute(userdata) };
+    if inner.reading_stream.get() && ErrorKind::from_num(errno) == ErrorKind::StreamEof {
+        return;
+    }
+
+    inner.last_errno.set(errno);
+    inner.last_detailno.set(detailno);
+
+    (inner.onerror)(ErrorKind::from_num(errno));
+}
@@ -1,244 +1,483 @@
-
-#[derive(Debug)]
-pub enum Error {
-    ArrayCount,
-    ArrayItemNotFound,
-    ArrayItemUnexpectedType,
-    BinaryLength,
-    CannotGetPallet,
-    DictCount,
-    DictItemNotFound,
-    DictItemUnexpectedType,
-    DictStreamLengthNotFound,
-    DocEncryptdictNotFound,
-    DocInvalidObject,
-    DuplicateRegistration,
-    ExceedJwwCodeNumLimit,
-    EncryptInvalidPassword,
-    UnknownClass,
-    ExceedGstateLimit,
-    FailedToAllocMem,
-    FileIo,
-    FileOpen,
-    FontExists,
-    FontInvalidWidthsTable,
-    InvalidAfmHeader,
-    InvalidAnnotation,
-    InvalidBitPerComponent,
-    InvalidCharMatricsData,
-    InvalidColorSpace,
-    InvalidCompressionMode,
-    InvalidDateTime,
-    InvalidDestination,
-    InvalidDocument,
-    InvalidDocumentState,
-    InvalidEncoder,
-    InvalidEncoderType,
-    InvalidEncodingName,
-    InvalidEncryptKeyLen,
-    InvalidFontdefData,
-    InvalidFOntDefType,
-    InvalidFontName,
-    InvalidImage,
-    InvalidJpegData,
-    InvalidNData,
-    InvalidObject,
-    InvalidObjectId,
-    InvalidOperation,
-    InvalidOutline,
-    InvalidPage,
-    InvalidPages,
-    InvalidParameter,
-    InvalidPngImage,
-    InvalidStream,
-    MissingFileNameEntry,
-    InvalidTtcFile,
-    InvalidTtcIndex,
-    InvalidWxData,
-    ItemNotFound,
-    Libpng,
-    NameInvalidValue,
-    NameOutOfRange,
-    PageInvalidParamCount,
-    PagesMissingKidsEntry,
-    PageCannotFindObject,
-    PageCannotGetRootPages,
-    PageCannotRestoreGstate,
-    PageCannotSetParent,
-    PageFontNotFound,
-    PageInvalidFont,
-    PageInvalidFontSize,
-    PageInvalidGMode,
-    PageInvalidIndex,
-    PageInvalidRotateValue,
-    PageInvalidSize,
-    PageInvalidXobject,
-    PageOutOfRange,
-    RealOutOfRange,
-    StreamEof,
-    StreamReadlnContinue,
-    StringOutOfRange,
-    ThisFuncWasSkipped,
-    TtfCannotEmbeddingFont,
-    TtfInvalidCmap,
-    TtfInvalidFormat,
-    TtfMissingTable,
-    UnsupportedFontType,
-    UnsupportedFunc,
-    UnsupportedJpegFormat,
-    UnsupportedType1Font,
-    XrefCount,
-    Zlib,
-    InvalidPageIndex,
-    InvalidUri,
-    PageLayoutOutOfRange,
-    PageModeOutOfRange,
-    PageNumStyleOutOfRange,
-    AnnotInvalidIcon,
-    AnnotInvalidBorderStyle,
-    PageInvalidDirection,
-    InvalidFont,
-    PageInsufficientSpace,
-    PageInvalidDisplayTime,
-    PageInvalidTransitionTime,
-    InvalidPageSlideshowType,
-    ExtGstateOutOfRange,
-    InvalidExtGstate,
-    ExtGstateReadOnly,
-    InvalidU3dData,
-    NameCannotGetNames,
-    InvalidIccComponentNum,
-    Other(libharu_sys::HPDF_STATUS)
-}
-
-impl Error {
-    pub(crate) fn from_num(num: libharu_sys::HPDF_STATUS) -> Self {
-        match num {
-            0x1001 => Self::ArrayCount,
-            0x1002 => Self::ArrayItemNotFound,
-            0x1003 => Self::ArrayItemUnexpectedType,
-            0x1004 => Self::BinaryLength,
-            0x1005 => Self::CannotGetPallet,
-            /* 0x1006 */
-            0x1007 => Self::DictCount,
-            0x1008 => Self::DictItemNotFound,
-            0x1009 => Self::DictItemUnexpectedType,
-            0x100A => Self::DictStreamLengthNotFound,
-            0x100B => Self::DocEncryptdictNotFound,
-            0x100C => Self::DocInvalidObject,
-            /* 0x100D */
-            0x100E => Self::DuplicateRegistration,
-            0x100F => Self::ExceedJwwCodeNumLimit,
-            /* 0x1010 */
-            0x1011 => Self::EncryptInvalidPassword,
-            /* 0x1012 */
-            0x1013 => Self::UnknownClass,
-            0x1014 => Self::ExceedGstateLimit,
-            0x1015 => Self::FailedToAllocMem,
-            0x1016 => Self::FileIo,
-            0x1017 => Self::FileOpen,
-            /* 0x1018 */
-            0x1019 => Self::FontExists,
-            0x101A => Self::FontInvalidWidthsTable,
-            0x101B => Self::InvalidAfmHeader,
-            0x101C => Self::InvalidAnnotation,
-            /* 0x101D */
-            0x101E => Self::InvalidBitPerComponent,
-            0x101F => Self::InvalidCharMatricsData,
-            0x1020 => Self::InvalidColorSpace,
-            0x1021 => Self::InvalidCompressionMode,
-            0x1022 => Self::InvalidDateTime,
-            0x1023 => Self::InvalidDestination,
-            /* 0x1024 */
-            0x1025 => Self::InvalidDocument,
-            0x1026 => Self::InvalidDocumentState,
-            0x1027 => Self::InvalidEncoder,
-            0x1028 => Self::InvalidEncoderType,
-            /* 0x1029 */
-            /* 0x102A */
-            0x102B => Self::InvalidEncodingName,
-            0x102C => Self::InvalidEncryptKeyLen,
-            0x102D => Self::InvalidFontdefData,
-            0x102E => Self::InvalidFOntDefType,
-            0x102F => Self::InvalidFontName,
-            0x1030 => Self::InvalidImage,
-            0x1031 => Self::InvalidJpegData,
-            0x1032 => Self::InvalidNData,
-            0x1033 => Self::InvalidObject,
-            0x1034 => Self::InvalidObjectId,
-            0x1035 => Self::InvalidOperation,
-            0x1036 => Self::InvalidOutline,
-            0x1037 => Self::InvalidPage,
-            0x1038 => Self::InvalidPages,
-            0x1039 => Self::InvalidParameter,
-            /* 0x103A */
-            0x103B => Self::InvalidPngImage,
-            0x103C => Self::InvalidStream,
-            0x103D => Self::MissingFileNameEntry,
-            /* 0x103E */
-            0x103F => Self::InvalidTtcFile,
-            0x1040 => Self::InvalidTtcIndex,
-            0x1041 => Self::InvalidWxData,
-            0x1042 => Self::ItemNotFound,
-            0x1043 => Self::Libpng,
-            0x1044 => Self::NameInvalidValue,
-            0x1045 => Self::NameOutOfRange,
-            /* 0x1046 */
-            /* 0x1047 */
-            0x1048 => Self::PageInvalidParamCount,
-            0x1049 => Self::PagesMissingKidsEntry,
-            0x104A => Self::PageCannotFindObject,
-            0x104B => Self::PageCannotGetRootPages,
-            0x104C => Self::PageCannotRestoreGstate,
-            0x104D => Self::PageCannotSetParent,
-            0x104E => Self::PageFontNotFound,
-            0x104F => Self::PageInvalidFont,
-            0x1050 => Self::PageInvalidFontSize,
-            0x1051 => Self::PageInvalidGMode,
-            0x1052 => Self::PageInvalidIndex,
-            0x1053 => Self::PageInvalidRotateValue,
-            0x1054 => Self::PageInvalidSize,
-            0x1055 => Self::PageInvalidXobject,
-            0x1056 => Self::PageOutOfRange,
-            0x1057 => Self::RealOutOfRange,
-            0x1058 => Self::StreamEof,
-            0x1059 => Self::StreamReadlnContinue,
-            /* 0x105A */
-            0x105B => Self::StringOutOfRange,
-            0x105C => Self::ThisFuncWasSkipped,
-            0x105D => Self::TtfCannotEmbeddingFont,
-            0x105E => Self::TtfInvalidCmap,
-            0x105F => Self::TtfInvalidFormat,
-            0x1060 => Self::TtfMissingTable,
-            0x1061 => Self::UnsupportedFontType,
-            0x1062 => Self::UnsupportedFunc,
-            0x1063 => Self::UnsupportedJpegFormat,
-            0x1064 => Self::UnsupportedType1Font,
-            0x1065 => Self::XrefCount,
-            0x1066 => Self::Zlib,
-            0x1067 => Self::InvalidPageIndex,
-            0x1068 => Self::InvalidUri,
-            0x1069 => Self::PageLayoutOutOfRange,
-            /* 0x106A-0x106F */
-            0x1070 => Self::PageModeOutOfRange,
-            0x1071 => Self::PageNumStyleOutOfRange,
-            0x1072 => Self::AnnotInvalidIcon,
-            0x1073 => Self::AnnotInvalidBorderStyle,
-            0x1074 => Self::PageInvalidDirection,
-            0x1075 => Self::InvalidFont,
-            0x1076 => Self::PageInsufficientSpace,
-            0x1077 => Self::PageInvalidDisplayTime,
-            0x1078 => Self::PageInvalidTransitionTime,
-            0x1079 => Self::InvalidPageSlideshowType,
-            /* 0x107A-0x107F */
-            0x1080 => Self::ExtGstateOutOfRange,
-            0x1081 => Self::InvalidExtGstate,
-            0x1082 => Self::ExtGstateReadOnly,
-            0x1083 => Self::InvalidU3dData,
-            0x1084 => Self::NameCannotGetNames,
-            0x1085 => Self::InvalidIccComponentNum,
-            
-            n => Self::Other(n),
-        }
-    }
-}
+use std::ffi::NulError;
+use std::fmt;
+use std::io;
+use std::num::TryFromIntError;
+use std::str::Utf8Error;
+
+/// Error codes reported by libharu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    ArrayCount,
+    ArrayItemNotFound,
+    ArrayItemUnexpectedType,
+    BinaryLength,
+    CannotGetPallet,
+    DictCount,
+    DictItemNotFound,
+    DictItemUnexpectedType,
+    DictStreamLengthNotFound,
+    DocEncryptdictNotFound,
+    DocInvalidObject,
+    DuplicateRegistration,
+    ExceedJwwCodeNumLimit,
+    EncryptInvalidPassword,
+    UnknownClass,
+    ExceedGstateLimit,
+    FailedToAllocMem,
+    FileIo,
+    FileOpen,
+    FontExists,
+    FontInvalidWidthsTable,
+    InvalidAfmHeader,
+    InvalidAnnotation,
+    InvalidBitPerComponent,
+    InvalidCharMatricsData,
+    InvalidColorSpace,
+    InvalidCompressionMode,
+    InvalidDateTime,
+    InvalidDestination,
+    InvalidDocument,
+    InvalidDocumentState,
+    InvalidEncoder,
+    InvalidEncoderType,
+    InvalidEncodingName,
+    InvalidEncryptKeyLen,
+    InvalidFontdefData,
+    InvalidFOntDefType,
+    InvalidFontName,
+    InvalidImage,
+    InvalidJpegData,
+    InvalidNData,
+    InvalidObject,
+    InvalidObjectId,
+    InvalidOperation,
+    InvalidOutline,
+    InvalidPage,
+    InvalidPages,
+    InvalidParameter,
+    InvalidPngImage,
+    InvalidStream,
+    MissingFileNameEntry,
+    InvalidTtcFile,
+    InvalidTtcIndex,
+    InvalidWxData,
+    ItemNotFound,
+    Libpng,
+    NameInvalidValue,
+    NameOutOfRange,
+    PageInvalidParamCount,
+    PagesMissingKidsEntry,
+    PageCannotFindObject,
+    PageCannotGetRootPages,
+    PageCannotRestoreGstate,
+    PageCannotSetParent,
+    PageFontNotFound,
+    PageInvalidFont,
+    PageInvalidFontSize,
+    PageInvalidGMode,
+    PageInvalidIndex,
+    PageInvalidRotateValue,
+    PageInvalidSize,
+    PageInvalidXobject,
+    PageOutOfRange,
+    RealOutOfRange,
+    StreamEof,
+    StreamReadlnContinue,
+    StringOutOfRange,
+    ThisFuncWasSkipped,
+    TtfCannotEmbeddingFont,
+    TtfInvalidCmap,
+    TtfInvalidFormat,
+    TtfMissingTable,
+    UnsupportedFontType,
+    UnsupportedFunc,
+    UnsupportedJpegFormat,
+    UnsupportedType1Font,
+    XrefCount,
+    Zlib,
+    InvalidPageIndex,
+    InvalidUri,
+    PageLayoutOutOfRange,
+    PageModeOutOfRange,
+    PageNumStyleOutOfRange,
+    AnnotInvalidIcon,
+    AnnotInvalidBorderStyle,
+    PageInvalidDirection,
+    InvalidFont,
+    PageInsufficientSpace,
+    PageInvalidDisplayTime,
+    PageInvalidTransitionTime,
+    InvalidPageSlideshowType,
+    ExtGstateOutOfRange,
+    InvalidExtGstate,
+    ExtGstateReadOnly,
+    InvalidU3dData,
+    NameCannotGetNames,
+    InvalidIccComponentNum,
+
+    /// A string passed to the binding contains an interior nul byte.
+    NulByte,
+
+    /// A string returned by libharu is not valid UTF-8.
+    InvalidUtf8,
+
+    /// SVG path data is invalid at the byte offset.
+    InvalidSvgPath(usize),
+
+    /// SVG document could not be parsed.
+    InvalidSvg,
+
+    /// Reading or writing the document data failed.
+    Io(io::ErrorKind),
+
+    /// Error code not known to this binding.
+    Other(libharu_sys::HPDF_STATUS),
+}
+
+impl ErrorKind {
+    pub(crate) fn from_num(num: libharu_sys::HPDF_STATUS) -> Self {
+        match num {
+            0x1001 => Self::ArrayCount,
+            0x1002 => Self::ArrayItemNotFound,
+            0x1003 => Self::ArrayItemUnexpectedType,
+            0x1004 => Self::BinaryLength,
+            0x1005 => Self::CannotGetPallet,
+            /* 0x1006 */
+            0x1007 => Self::DictCount,
+            0x1008 => Self::DictItemNotFound,
+            0x1009 => Self::DictItemUnexpectedType,
+            0x100A => Self::DictStreamLengthNotFound,
+            0x100B => Self::DocEncryptdictNotFound,
+            0x100C => Self::DocInvalidObject,
+            /* 0x100D */
+            0x100E => Self::DuplicateRegistration,
+            0x100F => Self::ExceedJwwCodeNumLimit,
+            /* 0x1010 */
+            0x1011 => Self::EncryptInvalidPassword,
+            /* 0x1012 */
+            0x1013 => Self::UnknownClass,
+            0x1014 => Self::ExceedGstateLimit,
+            0x1015 => Self::FailedToAllocMem,
+            0x1016 => Self::FileIo,
+            0x1017 => Self::FileOpen,
+            /* 0x1018 */
+            0x1019 => Self::FontExists,
+            0x101A => Self::FontInvalidWidthsTable,
+            0x101B => Self::InvalidAfmHeader,
+            0x101C => Self::InvalidAnnotation,
+            /* 0x101D */
+            0x101E => Self::InvalidBitPerComponent,
+            0x101F => Self::InvalidCharMatricsData,
+            0x1020 => Self::InvalidColorSpace,
+            0x1021 => Self::InvalidCompressionMode,
+            0x1022 => Self::InvalidDateTime,
+            0x1023 => Self::InvalidDestination,
+            /* 0x1024 */
+            0x1025 => Self::InvalidDocument,
+            0x1026 => Self::InvalidDocumentState,
+            0x1027 => Self::InvalidEncoder,
+            0x1028 => Self::InvalidEncoderType,
+            /* 0x1029 */
+            /* 0x102A */
+            0x102B => Self::InvalidEncodingName,
+            0x102C => Self::InvalidEncryptKeyLen,
+            0x102D => Self::InvalidFontdefData,
+            0x102E => Self::InvalidFOntDefType,
+            0x102F => Self::InvalidFontName,
+            0x1030 => Self::InvalidImage,
+            0x1031 => Self::InvalidJpegData,
+            0x1032 => Self::InvalidNData,
+            0x1033 => Self::InvalidObject,
+            0x1034 => Self::InvalidObjectId,
+            0x1035 => Self::InvalidOperation,
+            0x1036 => Self::InvalidOutline,
+            0x1037 => Self::InvalidPage,
+            0x1038 => Self::InvalidPages,
+            0x1039 => Self::InvalidParameter,
+            /* 0x103A */
+            0x103B => Self::InvalidPngImage,
+            0x103C => Self::InvalidStream,
+            0x103D => Self::MissingFileNameEntry,
+            /* 0x103E */
+            0x103F => Self::InvalidTtcFile,
+            0x1040 => Self::InvalidTtcIndex,
+            0x1041 => Self::InvalidWxData,
+            0x1042 => Self::ItemNotFound,
+            0x1043 => Self::Libpng,
+            0x1044 => Self::NameInvalidValue,
+            0x1045 => Self::NameOutOfRange,
+            /* 0x1046 */
+            /* 0x1047 */
+            0x1048 => Self::PageInvalidParamCount,
+            0x1049 => Self::PagesMissingKidsEntry,
+            0x104A => Self::PageCannotFindObject,
+            0x104B => Self::PageCannotGetRootPages,
+            0x104C => Self::PageCannotRestoreGstate,
+            0x104D => Self::PageCannotSetParent,
+            0x104E => Self::PageFontNotFound,
+            0x104F => Self::PageInvalidFont,
+            0x1050 => Self::PageInvalidFontSize,
+            0x1051 => Self::PageInvalidGMode,
+            0x1052 => Self::PageInvalidIndex,
+            0x1053 => Self::PageInvalidRotateValue,
+            0x1054 => Self::PageInvalidSize,
+            0x1055 => Self::PageInvalidXobject,
+            0x1056 => Self::PageOutOfRange,
+            0x1057 => Self::RealOutOfRange,
+            0x1058 => Self::StreamEof,
+            0x1059 => Self::StreamReadlnContinue,
+            /* 0x105A */
+            0x105B => Self::StringOutOfRange,
+            0x105C => Self::ThisFuncWasSkipped,
+            0x105D => Self::TtfCannotEmbeddingFont,
+            0x105E => Self::TtfInvalidCmap,
+            0x105F => Self::TtfInvalidFormat,
+            0x1060 => Self::TtfMissingTable,
+            0x1061 => Self::UnsupportedFontType,
+            0x1062 => Self::UnsupportedFunc,
+            0x1063 => Self::UnsupportedJpegFormat,
+            0x1064 => Self::UnsupportedType1Font,
+            0x1065 => Self::XrefCount,
+            0x1066 => Self::Zlib,
+            0x1067 => Self::InvalidPageIndex,
+            0x1068 => Self::InvalidUri,
+            0x1069 => Self::PageLayoutOutOfRange,
+            /* 0x106A-0x106F */
+            0x1070 => Self::PageModeOutOfRange,
+            0x1071 => Self::PageNumStyleOutOfRange,
+            0x1072 => Self::AnnotInvalidIcon,
+            0x1073 => Self::AnnotInvalidBorderStyle,
+            0x1074 => Self::PageInvalidDirection,
+            0x1075 => Self::InvalidFont,
+            0x1076 => Self::PageInsufficientSpace,
+            0x1077 => Self::PageInvalidDisplayTime,
+            0x1078 => Self::PageInvalidTransitionTime,
+            0x1079 => Self::InvalidPageSlideshowType,
+            /* 0x107A-0x107F */
+            0x1080 => Self::ExtGstateOutOfRange,
+            0x1081 => Self::InvalidExtGstate,
+            0x1082 => Self::ExtGstateReadOnly,
+            0x1083 => Self::InvalidU3dData,
+            0x1084 => Self::NameCannotGetNames,
+            0x1085 => Self::InvalidIccComponentNum,
+
+            n => Self::Other(n),
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::ArrayCount => "array element count exceeds the limit",
+            Self::ArrayItemNotFound => "array item not found",
+            Self::ArrayItemUnexpectedType => "array item has an unexpected type",
+            Self::BinaryLength => "binary data exceeds the maximum length",
+            Self::CannotGetPallet => "cannot get the palette of the image",
+            Self::DictCount => "dictionary element count exceeds the limit",
+            Self::DictItemNotFound => "dictionary item not found",
+            Self::DictItemUnexpectedType => "dictionary item has an unexpected type",
+            Self::DictStreamLengthNotFound => "dictionary stream length not found",
+            Self::DocEncryptdictNotFound => {
+                "encryption is enabled but the encrypt dictionary is missing"
+            }
+            Self::DocInvalidObject => "invalid object in the document",
+            Self::DuplicateRegistration => "object is already registered",
+            Self::ExceedJwwCodeNumLimit => "too many JWW code numbers",
+            Self::EncryptInvalidPassword => "owner password is empty or equal to the user password",
+            Self::UnknownClass => "unknown object class",
+            Self::ExceedGstateLimit => "graphics state stack depth exceeds the limit",
+            Self::FailedToAllocMem => "memory allocation failed",
+            Self::FileIo => "file I/O error",
+            Self::FileOpen => "cannot open the file",
+            Self::FontExists => "a font with the same name is already registered",
+            Self::FontInvalidWidthsTable => "invalid widths table in the font definition",
+            Self::InvalidAfmHeader => "invalid AFM file header",
+            Self::InvalidAnnotation => "invalid annotation object",
+            Self::InvalidBitPerComponent => "invalid bits per component for the image",
+            Self::InvalidCharMatricsData => "invalid character metrics data in the AFM file",
+            Self::InvalidColorSpace => "invalid color space",
+            Self::InvalidCompressionMode => "invalid compression mode",
+            Self::InvalidDateTime => "invalid date or time value",
+            Self::InvalidDestination => "invalid destination object",
+            Self::InvalidDocument => "invalid document handle",
+            Self::InvalidDocumentState => "operation is not allowed in the current document state",
+            Self::InvalidEncoder => "invalid encoder object",
+            Self::InvalidEncoderType => "encoder type does not support this operation",
+            Self::InvalidEncodingName => "unknown encoding name",
+            Self::InvalidEncryptKeyLen => "invalid encryption key length",
+            Self::InvalidFontdefData => "invalid font definition data",
+            Self::InvalidFOntDefType => "unsupported font definition type",
+            Self::InvalidFontName => "unknown font name",
+            Self::InvalidImage => "unsupported or invalid image format",
+            Self::InvalidJpegData => "invalid JPEG data",
+            Self::InvalidNData => "cannot read a postscript name from the font",
+            Self::InvalidObject => "invalid object handle",
+            Self::InvalidObjectId => "invalid object id",
+            Self::InvalidOperation => "invalid operation",
+            Self::InvalidOutline => "invalid outline object",
+            Self::InvalidPage => "invalid page object",
+            Self::InvalidPages => "invalid pages object",
+            Self::InvalidParameter => "invalid parameter",
+            Self::InvalidPngImage => "invalid PNG image",
+            Self::InvalidStream => "invalid stream",
+            Self::MissingFileNameEntry => "file name entry is missing",
+            Self::InvalidTtcFile => "invalid TrueType collection file",
+            Self::InvalidTtcIndex => "TrueType collection index out of range",
+            Self::InvalidWxData => "invalid glyph width data in the AFM file",
+            Self::ItemNotFound => "item not found",
+            Self::Libpng => "libpng reported an error",
+            Self::NameInvalidValue => "invalid name value",
+            Self::NameOutOfRange => "name is too long",
+            Self::PageInvalidParamCount => "invalid page parameter count",
+            Self::PagesMissingKidsEntry => "pages object is missing its kids entry",
+            Self::PageCannotFindObject => "cannot find the page object",
+            Self::PageCannotGetRootPages => "cannot get the root pages object",
+            Self::PageCannotRestoreGstate => "there are no graphics states to restore",
+            Self::PageCannotSetParent => "cannot set the parent of the page",
+            Self::PageFontNotFound => "no font is set on the page",
+            Self::PageInvalidFont => "invalid font handle",
+            Self::PageInvalidFontSize => "invalid font size",
+            Self::PageInvalidGMode => "function is not allowed in the current graphics mode",
+            Self::PageInvalidIndex => "invalid page index",
+            Self::PageInvalidRotateValue => "rotation angle must be a multiple of 90",
+            Self::PageInvalidSize => "invalid page size",
+            Self::PageInvalidXobject => "invalid XObject handle",
+            Self::PageOutOfRange => "value is out of range",
+            Self::RealOutOfRange => "real value is out of range",
+            Self::StreamEof => "unexpected end of stream",
+            Self::StreamReadlnContinue => "line read from stream was truncated",
+            Self::StringOutOfRange => "string is too long",
+            Self::ThisFuncWasSkipped => "function was skipped because of a previous error",
+            Self::TtfCannotEmbeddingFont => "font license does not allow embedding",
+            Self::TtfInvalidCmap => "unsupported TrueType cmap table",
+            Self::TtfInvalidFormat => "unsupported TrueType format",
+            Self::TtfMissingTable => "required TrueType table is missing",
+            Self::UnsupportedFontType => "unsupported font type",
+            Self::UnsupportedFunc => "function is not supported by this build of libharu",
+            Self::UnsupportedJpegFormat => "unsupported JPEG format",
+            Self::UnsupportedType1Font => "cannot read the Type1 font program",
+            Self::XrefCount => "cross-reference count exceeds the limit",
+            Self::Zlib => "zlib reported an error",
+            Self::InvalidPageIndex => "page index is out of range",
+            Self::InvalidUri => "invalid URI",
+            Self::PageLayoutOutOfRange => "page layout value is out of range",
+            Self::PageModeOutOfRange => "page mode value is out of range",
+            Self::PageNumStyleOutOfRange => "page number style is out of range",
+            Self::AnnotInvalidIcon => "invalid annotation icon",
+            Self::AnnotInvalidBorderStyle => "invalid annotation border style",
+            Self::PageInvalidDirection => "invalid page direction",
+            Self::InvalidFont => "invalid font handle",
+            Self::PageInsufficientSpace => "not enough space to print the text",
+            Self::PageInvalidDisplayTime => "invalid slideshow display time",
+            Self::PageInvalidTransitionTime => "invalid slideshow transition time",
+            Self::InvalidPageSlideshowType => "invalid slideshow type",
+            Self::ExtGstateOutOfRange => "extended graphics state value is out of range",
+            Self::InvalidExtGstate => "invalid extended graphics state handle",
+            Self::ExtGstateReadOnly => "extended graphics state is read-only once used",
+            Self::InvalidU3dData => "invalid U3D data",
+            Self::NameCannotGetNames => "cannot get the names dictionary",
+            Self::InvalidIccComponentNum => "invalid number of ICC color components",
+            Self::NulByte => "string contains an interior nul byte",
+            Self::InvalidUtf8 => "string is not valid UTF-8",
+            Self::InvalidSvg => "invalid SVG document",
+            Self::InvalidSvgPath(pos) => return write!(f, "invalid SVG path data at byte {}", pos),
+            Self::Io(kind) => return write!(f, "I/O error ({:?})", kind),
+            Self::Other(n) => return write!(f, "unknown libharu error (0x{:04X})", n),
+        };
+
+        f.write_str(msg)
+    }
+}
+
+/// Error returned from the functions of this crate.
+///
+/// Carries the decoded libharu error code, the detail number libharu reported
+/// alongside it and the name of the C function that failed.
+#[derive(Debug, Clone)]
+pub struct Error {
+    kind: ErrorKind,
+    detail_no: libharu_sys::HPDF_STATUS,
+    function: Option<&'static str>,
+}
+
+impl Error {
+    pub(crate) fn new(
+        kind: ErrorKind,
+        detail_no: libharu_sys::HPDF_STATUS,
+        function: Option<&'static str>,
+    ) -> Self {
+        Self {
+            kind,
+            detail_no,
+            function,
+        }
+    }
+
+    /// Get the decoded error code.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Get the detail number libharu reported with the error (0 if none).
+    pub fn detail_no(&self) -> libharu_sys::HPDF_STATUS {
+        self.detail_no
+    }
+
+    /// Get the name of the libharu function that failed, if any.
+    pub fn function(&self) -> Option<&'static str> {
+        self.function
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(function) = self.function {
+            write!(f, "{} failed: ", function)?;
+        }
+
+        write!(f, "{}", self.kind)?;
+
+        if self.detail_no != 0 {
+            write!(f, " (detail={})", self.detail_no)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind, 0, None)
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(_: NulError) -> Self {
+        ErrorKind::NulByte.into()
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(_: Utf8Error) -> Self {
+        ErrorKind::InvalidUtf8.into()
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        /* errors raised by `DocumentReader` carry the original libharu error. */
+        match e.get_ref().and_then(|inner| inner.downcast_ref::<Error>()) {
+            Some(inner) => inner.clone(),
+            None => ErrorKind::Io(e.kind()).into(),
+        }
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(_: TryFromIntError) -> Self {
+        ErrorKind::InvalidParameter.into()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::document::Document;
+use crate::{Real, Result};
+
+/// Blend mode used to composite colors with the backdrop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Normal
+    Normal,
+
+    /// Multiply
+    Multiply,
+
+    /// Screen
+    Screen,
+
+    /// Overlay
+    Overlay,
+
+    /// Darken
+    Darken,
+
+    /// Lighten
+    Lighten,
+
+    /// Color dodge
+    ColorDodge,
+
+    /// Color burn
+    ColorBurn,
+
+    /// Hard light
+    HardLight,
+
+    /// Soft light
+    SoftLight,
+
+    /// Difference
+    Difference,
+
+    /// Exclusion
+    Exclusion,
+}
+
+impl From<BlendMode> for libharu_sys::HPDF_BlendMode {
+    fn from(value: BlendMode) -> Self {
+        use libharu_sys::HPDF_BlendMode::*;
+        use BlendMode::*;
+        match value {
+            Normal => HPDF_BM_NORMAL,
+            Multiply => HPDF_BM_MULTIPLY,
+            Screen => HPDF_BM_SCREEN,
+            Overlay => HPDF_BM_OVERLAY,
+            Darken => HPDF_BM_DARKEN,
+            Lighten => HPDF_BM_LIGHTEN,
+            ColorDodge => HPDF_BM_COLOR_DODGE,
+            ColorBurn => HPDF_BM_COLOR_BUM,
+            HardLight => HPDF_BM_HARD_LIGHT,
+            SoftLight => HPDF_BM_SOFT_LIGHT,
+            Difference => HPDF_BM_DIFFERENCE,
+            Exclusion => HPDF_BM_EXCLUSHON,
+        }
+    }
+}
+
+/// Extended graphics state handle type.
+///
+/// The values can not be changed once the state is used on a page.
+pub struct ExtGState<'a> {
+    gstate: libharu_sys::HPDF_ExtGState,
+    doc: &'a Document,
+}
+
+impl<'a> ExtGState<'a> {
+    pub(crate) fn new(doc: &'a Document, gstate: libharu_sys::HPDF_ExtGState) -> Self {
+        Self { gstate, doc }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_ExtGState {
+        self.gstate
+    }
+
+    /// Set the alpha value for filling. (0.0 ~ 1.0)
+    pub fn set_alpha_fill(&self, value: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_ExtGState_SetAlphaFill(self.handle(), value) };
+
+        if status != 0 {
+            return Err(self.doc.status_error("HPDF_ExtGState_SetAlphaFill", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the alpha value for stroking. (0.0 ~ 1.0)
+    pub fn set_alpha_stroke(&self, value: Real) -> Result<()> {
+        let status = unsafe { libharu_sys::HPDF_ExtGState_SetAlphaStroke(self.handle(), value) };
+
+        if status != 0 {
+            return Err(self
+                .doc
+                .status_error("HPDF_ExtGState_SetAlphaStroke", status));
+        }
+
+        Ok(())
+    }
+
+    /// Set the blend mode.
+    pub fn set_blend_mode(&self, mode: BlendMode) -> Result<()> {
+        let status =
+            unsafe { libharu_sys::HPDF_ExtGState_SetBlendMode(self.handle(), mode.into()) };
+
+        if status != 0 {
+            return Err(self.doc.status_error("HPDF_ExtGState_SetBlendMode", status));
+        }
+
+        Ok(())
+    }
+}
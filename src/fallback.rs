@@ -0,0 +1,423 @@
+use crate::error::ErrorKind;
+use crate::{Font, Result};
+
+/// Characters a TrueType font has glyphs for, read from its cmap table.
+#[derive(Debug, Clone, Default)]
+pub struct CharCoverage {
+    /* sorted, non-overlapping inclusive ranges of code points. */
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharCoverage {
+    /// Read the coverage of a TrueType font.
+    pub fn from_ttf(data: &[u8]) -> Result<Self> {
+        Self::from_offset(data, 0)
+    }
+
+    /// Read the coverage of a font in a TrueType collection.
+    pub fn from_ttc(data: &[u8], index: usize) -> Result<Self> {
+        if data.get(0..4) != Some(b"ttcf") {
+            return Err(ErrorKind::InvalidTtcFile.into());
+        }
+
+        let num_fonts = read_u32(data, 8)? as usize;
+        if index >= num_fonts {
+            return Err(ErrorKind::InvalidTtcIndex.into());
+        }
+
+        let offset = read_u32(data, 12 + 4 * index)? as usize;
+        Self::from_offset(data, offset)
+    }
+
+    /// Check whether the font has a glyph for the character.
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if end < c {
+                    std::cmp::Ordering::Less
+                } else if start > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    fn from_offset(data: &[u8], offset: usize) -> Result<Self> {
+        let num_tables = read_u16(data, offset + 4)? as usize;
+        let cmap = (0..num_tables)
+            .map(|i| offset + 12 + 16 * i)
+            .find(|&rec| data.get(rec..rec + 4) == Some(b"cmap"))
+            .ok_or(ErrorKind::TtfMissingTable)?;
+        let cmap = read_u32(data, cmap + 8)? as usize;
+
+        /* prefer a full unicode subtable (format 12) over a BMP one (format 4). */
+        let mut bmp = None;
+        let mut full = None;
+        for i in 0..read_u16(data, cmap + 2)? as usize {
+            let rec = cmap + 4 + 8 * i;
+            let platform = read_u16(data, rec)?;
+            let encoding = read_u16(data, rec + 2)?;
+            let subtable = cmap + read_u32(data, rec + 4)? as usize;
+
+            match (platform, encoding, read_u16(data, subtable)?) {
+                (0, _, 12) | (3, 10, 12) => full = Some(subtable),
+                (0, _, 4) | (3, 1, 4) => bmp = Some(subtable),
+                _ => {}
+            }
+        }
+
+        let mut coverage = Self::default();
+        match (full, bmp) {
+            (Some(subtable), _) => coverage.read_format12(data, subtable)?,
+            (None, Some(subtable)) => coverage.read_format4(data, subtable)?,
+            (None, None) => return Err(ErrorKind::TtfInvalidCmap.into()),
+        }
+        coverage.merge();
+
+        Ok(coverage)
+    }
+
+    fn read_format4(&mut self, data: &[u8], subtable: usize) -> Result<()> {
+        let seg_count = read_u16(data, subtable + 6)? as usize / 2;
+        let end_codes = subtable + 14;
+        let start_codes = end_codes + 2 * seg_count + 2;
+        let id_deltas = start_codes + 2 * seg_count;
+        let id_range_offsets = id_deltas + 2 * seg_count;
+
+        for seg in 0..seg_count {
+            let end = read_u16(data, end_codes + 2 * seg)?;
+            let start = read_u16(data, start_codes + 2 * seg)?;
+            let delta = read_u16(data, id_deltas + 2 * seg)?;
+            let range_offset_pos = id_range_offsets + 2 * seg;
+            let range_offset = read_u16(data, range_offset_pos)? as usize;
+
+            if start > end {
+                return Err(ErrorKind::TtfInvalidCmap.into());
+            }
+
+            for code in start..=end {
+                if code == 0xFFFF {
+                    break;
+                }
+
+                let gid = if range_offset == 0 {
+                    code.wrapping_add(delta)
+                } else {
+                    let pos = range_offset_pos + range_offset + 2 * (code - start) as usize;
+                    match read_u16(data, pos)? {
+                        0 => 0,
+                        gid => gid.wrapping_add(delta),
+                    }
+                };
+
+                if gid != 0 {
+                    self.push(code as u32, code as u32);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_format12(&mut self, data: &[u8], subtable: usize) -> Result<()> {
+        let num_groups = read_u32(data, subtable + 12)? as usize;
+
+        for i in 0..num_groups {
+            let group = subtable + 16 + 12 * i;
+            let start = read_u32(data, group)?;
+            let end = read_u32(data, group + 4)?;
+
+            if start > end || end > char::MAX as u32 {
+                return Err(ErrorKind::TtfInvalidCmap.into());
+            }
+
+            /* a group starting at glyph 0 maps its first code to notdef. */
+            if read_u32(data, group + 8)? != 0 {
+                self.push(start, end);
+            } else if start < end {
+                self.push(start + 1, end);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, start: u32, end: u32) {
+        match self.ranges.last_mut() {
+            Some(last) if last.1.checked_add(1) == Some(start) => last.1 = end,
+            _ => self.ranges.push((start, end)),
+        }
+    }
+
+    fn merge(&mut self) {
+        self.ranges.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len());
+        for &(start, end) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if last.1.saturating_add(1) >= start => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        self.ranges = merged;
+    }
+}
+
+fn read_u16(data: &[u8], pos: usize) -> Result<u16> {
+    match data.get(pos..pos + 2) {
+        Some(b) => Ok(u16::from_be_bytes([b[0], b[1]])),
+        None => Err(ErrorKind::TtfInvalidFormat.into()),
+    }
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Result<u32> {
+    match data.get(pos..pos + 4) {
+        Some(b) => Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]])),
+        None => Err(ErrorKind::TtfInvalidFormat.into()),
+    }
+}
+
+/// Ordered list of fonts used to show text which a single font can not cover.
+///
+/// Each character is shown with the first font in the chain having a glyph for it.
+#[derive(Debug, Default)]
+pub struct FontFallback<'a> {
+    fonts: Vec<(Font<'a>, Option<CharCoverage>)>,
+}
+
+impl<'a> FontFallback<'a> {
+    /// Create an empty fallback chain.
+    pub fn new() -> Self {
+        Self { fonts: Vec::new() }
+    }
+
+    /// Add a font to the end of the chain.
+    ///
+    /// A font without coverage is assumed to have a glyph for every character.
+    pub fn add_font(&mut self, font: Font<'a>, coverage: Option<CharCoverage>) -> &mut Self {
+        self.fonts.push((font, coverage));
+        self
+    }
+
+    /// Split the text into runs shown with the same font.
+    ///
+    /// Characters no font has a glyph for are shown with the first font.
+    pub(crate) fn runs<'t>(&self, text: &'t str) -> Result<Vec<(&Font<'a>, &'t str)>> {
+        if self.fonts.is_empty() {
+            return Err(ErrorKind::InvalidFont.into());
+        }
+
+        let mut runs: Vec<(usize, &'t str)> = Vec::new();
+        let mut run_start = 0;
+        let mut run_font = None;
+
+        for (pos, c) in text.char_indices() {
+            let font = self
+                .fonts
+                .iter()
+                .position(|(_, coverage)| match coverage {
+                    Some(coverage) => coverage.contains(c),
+                    None => true,
+                })
+                .unwrap_or(0);
+
+            match run_font {
+                Some(current) if current == font => {}
+                Some(current) => {
+                    runs.push((current, &text[run_start..pos]));
+                    run_start = pos;
+                    run_font = Some(font);
+                }
+                None => run_font = Some(font),
+            }
+        }
+
+        if let Some(current) = run_font {
+            runs.push((current, &text[run_start..]));
+        }
+
+        let runs = runs
+            .into_iter()
+            .map(|(font, run)| (&self.fonts[font].0, run))
+            .collect();
+
+        Ok(runs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::Document;
+
+    /* font with only a cmap table holding the given subtable, as a (3, 10) or (3, 1) record. */
+    fn font_with_cmap(subtable: &[u8]) -> Vec<u8> {
+        let format = u16::from_be_bytes([subtable[0], subtable[1]]);
+        let encoding: u16 = if format == 12 { 10 } else { 1 };
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        data.extend_from_slice(&1u16.to_be_bytes());
+        data.extend_from_slice(&[0; 6]);
+        data.extend_from_slice(b"cmap");
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&28u32.to_be_bytes());
+        data.extend_from_slice(&(12 + subtable.len() as u32).to_be_bytes());
+
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.extend_from_slice(&1u16.to_be_bytes());
+        data.extend_from_slice(&3u16.to_be_bytes());
+        data.extend_from_slice(&encoding.to_be_bytes());
+        data.extend_from_slice(&12u32.to_be_bytes());
+        data.extend_from_slice(subtable);
+
+        data
+    }
+
+    /* format 4 subtable from (start, end, delta) segments, followed by the final 0xFFFF one. */
+    fn format4(segments: &[(u16, u16, u16)]) -> Vec<u8> {
+        let mut segments = segments.to_vec();
+        segments.push((0xFFFF, 0xFFFF, 1));
+        let seg_count = segments.len() as u16;
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&4u16.to_be_bytes());
+        data.extend_from_slice(&(16 + 8 * seg_count).to_be_bytes());
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.extend_from_slice(&(2 * seg_count).to_be_bytes());
+        data.extend_from_slice(&[0; 6]);
+        for &(_, end, _) in &segments {
+            data.extend_from_slice(&end.to_be_bytes());
+        }
+        data.extend_from_slice(&0u16.to_be_bytes());
+        for &(start, _, _) in &segments {
+            data.extend_from_slice(&start.to_be_bytes());
+        }
+        for &(_, _, delta) in &segments {
+            data.extend_from_slice(&delta.to_be_bytes());
+        }
+        for _ in &segments {
+            data.extend_from_slice(&0u16.to_be_bytes());
+        }
+
+        data
+    }
+
+    /* format 12 subtable from (start, end, start glyph) groups. */
+    fn format12(groups: &[(u32, u32, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&12u16.to_be_bytes());
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.extend_from_slice(&(16 + 12 * groups.len() as u32).to_be_bytes());
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&(groups.len() as u32).to_be_bytes());
+        for &(start, end, glyph) in groups {
+            data.extend_from_slice(&start.to_be_bytes());
+            data.extend_from_slice(&end.to_be_bytes());
+            data.extend_from_slice(&glyph.to_be_bytes());
+        }
+
+        data
+    }
+
+    #[test]
+    fn format4_coverage() {
+        /* 'A'..='Z' map to glyphs 1.., while a delta of 0x10000 - '0' maps '0' to notdef. */
+        let data = font_with_cmap(&format4(&[(0x30, 0x39, 0xFFD0), (0x41, 0x5A, 0xFFC0)]));
+        let coverage = CharCoverage::from_ttf(&data).unwrap();
+
+        assert!(coverage.contains('A'));
+        assert!(coverage.contains('Z'));
+        assert!(coverage.contains('1'));
+        assert!(!coverage.contains('0'));
+        assert!(!coverage.contains('a'));
+        assert!(!coverage.contains('\u{FFFF}'));
+    }
+
+    #[test]
+    fn format12_coverage() {
+        let data = font_with_cmap(&format12(&[(0x20, 0x7E, 0), (0x1F600, 0x1F64F, 100)]));
+        let coverage = CharCoverage::from_ttf(&data).unwrap();
+
+        assert!(!coverage.contains(' '));
+        assert!(coverage.contains('!'));
+        assert!(coverage.contains('~'));
+        assert!(coverage.contains('\u{1F600}'));
+        assert!(!coverage.contains('\u{1F650}'));
+    }
+
+    #[test]
+    fn format12_group_bounds() {
+        let data = font_with_cmap(&format12(&[
+            (0x10FFFF, 0x10FFFF, 0),
+            (0x10FFFE, 0x10FFFF, 1),
+        ]));
+        let coverage = CharCoverage::from_ttf(&data).unwrap();
+        assert!(coverage.contains('\u{10FFFF}'));
+
+        for &group in &[
+            (0x20, 0x10, 1),
+            (0x20, 0x11_0000, 1),
+            (u32::MAX, u32::MAX, 0),
+        ] {
+            let data = font_with_cmap(&format12(&[group]));
+            let err = CharCoverage::from_ttf(&data).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::TtfInvalidCmap);
+        }
+    }
+
+    #[test]
+    fn malformed_lengths_are_rejected() {
+        let data = font_with_cmap(&format4(&[(0x41, 0x5A, 0xFFC0)]));
+        for len in &[0, 3, 20, 40, data.len() - 1] {
+            let err = CharCoverage::from_ttf(&data[..*len]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::TtfInvalidFormat);
+        }
+
+        /* more groups than the subtable holds. */
+        let mut data = font_with_cmap(&format12(&[(0x41, 0x5A, 1)]));
+        data[52..56].copy_from_slice(&1000u32.to_be_bytes());
+        let err = CharCoverage::from_ttf(&data).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TtfInvalidFormat);
+
+        let err = CharCoverage::from_ttc(&data, 0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidTtcFile);
+    }
+
+    #[test]
+    fn fallback_selection() {
+        let doc = Document::new(|_| {}).unwrap();
+        let latin = doc.font("Helvetica", None).unwrap();
+        let digits = doc.font("Courier", None).unwrap();
+        let data = font_with_cmap(&format12(&[(0x41, 0x5A, 1), (0x61, 0x7A, 27)]));
+
+        let mut fallback = FontFallback::new();
+        assert_eq!(
+            fallback.runs("a").unwrap_err().kind(),
+            ErrorKind::InvalidFont
+        );
+
+        fallback
+            .add_font(latin, Some(CharCoverage::from_ttf(&data).unwrap()))
+            .add_font(digits, None);
+
+        let runs = fallback.runs("ab12Cd").unwrap();
+        let runs: Vec<(usize, &str)> = runs
+            .into_iter()
+            .map(|(font, run)| {
+                let index = fallback
+                    .fonts
+                    .iter()
+                    .position(|(f, _)| std::ptr::eq(f, font))
+                    .unwrap();
+                (index, run)
+            })
+            .collect();
+        assert_eq!(runs, vec![(0, "ab"), (1, "12"), (0, "Cd")]);
+        assert!(fallback.runs("").unwrap().is_empty());
+    }
+}
@@ -1,37 +1,178 @@
-use crate::document::Document;
-use crate::Real;
-
-/// Image handle type.
-pub struct Image<'a> {
-    _doc: &'a Document,
-    img: libharu_sys::HPDF_Image,
-}
-
-impl<'a> Image<'a> {
-    pub(crate) fn new(_doc: &'a Document, img: libharu_sys::HPDF_Destination) -> Self {
-        Self { _doc, img }
-    }
-    
-    #[inline]
-    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Destination {
-        self.img
-    }
-    
-    /// Get the width of the image of an image object.
-    pub fn width(&self) -> anyhow::Result<Real> {
-        let ret = unsafe {
-            libharu_sys::HPDF_Image_GetWidth(self.handle())
-        };
-
-        Ok(ret as Real)
-    }
-
-    /// Get the height of the image of an image object.
-    pub fn height(&self) -> anyhow::Result<Real> {
-        let ret = unsafe {
-            libharu_sys::HPDF_Image_GetHeight(self.handle())
-        };
-
-        Ok(ret as Real)
-    }
-}
+use crate::document::Document;
+use crate::error::{Error, ErrorKind};
+use crate::{Point, Real, Result};
+
+use std::ffi::CStr;
+
+/// Color space of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Grayscale.
+    DeviceGray,
+
+    /// RGB.
+    DeviceRGB,
+
+    /// CMYK.
+    DeviceCMYK,
+
+    /// Palette based color.
+    Indexed,
+
+    /// Color described by an ICC profile.
+    ICCBased,
+}
+
+impl From<ColorSpace> for libharu_sys::HPDF_ColorSpace {
+    fn from(value: ColorSpace) -> Self {
+        match value {
+            ColorSpace::DeviceGray => libharu_sys::HPDF_ColorSpace::HPDF_CS_DEVICE_GRAY,
+            ColorSpace::DeviceRGB => libharu_sys::HPDF_ColorSpace::HPDF_CS_DEVICE_RGB,
+            ColorSpace::DeviceCMYK => libharu_sys::HPDF_ColorSpace::HPDF_CS_DEVICE_CMYK,
+            ColorSpace::Indexed => libharu_sys::HPDF_ColorSpace::HPDF_CS_INDEXED,
+            ColorSpace::ICCBased => libharu_sys::HPDF_ColorSpace::HPDF_CS_ICC_BASED,
+        }
+    }
+}
+
+/// Image handle type.
+pub struct Image<'a> {
+    doc: &'a Document,
+    img: libharu_sys::HPDF_Image,
+}
+
+impl<'a> Image<'a> {
+    pub(crate) fn new(doc: &'a Document, img: libharu_sys::HPDF_Destination) -> Self {
+        Self { doc, img }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Destination {
+        self.img
+    }
+
+    /// Get the size of the image of an image object.
+    pub fn size(&self) -> Result<Point> {
+        let ret = unsafe {
+            libharu_sys::HPDF_Image_GetSize(self.handle())
+        };
+
+        Ok(ret.into())
+    }
+
+    /// Get the width of the image of an image object.
+    pub fn width(&self) -> Result<Real> {
+        let ret = unsafe {
+            libharu_sys::HPDF_Image_GetWidth(self.handle())
+        };
+
+        Ok(ret as Real)
+    }
+
+    /// Get the height of the image of an image object.
+    pub fn height(&self) -> Result<Real> {
+        let ret = unsafe {
+            libharu_sys::HPDF_Image_GetHeight(self.handle())
+        };
+
+        Ok(ret as Real)
+    }
+
+    /// Get the number of bits used to describe each color component.
+    pub fn bits_per_component(&self) -> Result<u32> {
+        self.doc.reset_last_error();
+        let ret = unsafe {
+            libharu_sys::HPDF_Image_GetBitsPerComponent(self.handle())
+        };
+
+        if ret == 0 {
+            return Err(self.doc.last_error("HPDF_Image_GetBitsPerComponent"));
+        }
+
+        Ok(ret)
+    }
+
+    /// Get the color space of the image.
+    pub fn color_space(&self) -> Result<ColorSpace> {
+        self.doc.reset_last_error();
+        let name = unsafe {
+            libharu_sys::HPDF_Image_GetColorSpace(self.handle())
+        };
+
+        if name.is_null() {
+            return Err(self.doc.last_error("HPDF_Image_GetColorSpace"));
+        }
+
+        let name = unsafe { CStr::from_ptr(name) }.to_str()?;
+
+        Ok(match name {
+            "DeviceGray" => ColorSpace::DeviceGray,
+            "DeviceRGB" => ColorSpace::DeviceRGB,
+            "DeviceCMYK" => ColorSpace::DeviceCMYK,
+            "Indexed" => ColorSpace::Indexed,
+            "ICCBased" => ColorSpace::ICCBased,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidColorSpace,
+                    0,
+                    Some("HPDF_Image_GetColorSpace"),
+                ))
+            }
+        })
+    }
+
+    /// Make the pixels whose color lies in the given ranges transparent. (RGB images only)
+    pub fn set_color_mask(
+        &self,
+        rmin: u32,
+        rmax: u32,
+        gmin: u32,
+        gmax: u32,
+        bmin: u32,
+        bmax: u32,
+    ) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_Image_SetColorMask(
+                self.handle(),
+                rmin,
+                rmax,
+                gmin,
+                gmax,
+                bmin,
+                bmax,
+            )
+        };
+
+        if status != 0 {
+            return Err(self.doc.status_error("HPDF_Image_SetColorMask", status));
+        }
+
+        Ok(())
+    }
+
+    /// Mask the image with another image.
+    ///
+    /// A 1 bit image is used as a stencil mask. Any other mask must be a grayscale image,
+    /// which is used as a soft mask.
+    pub fn set_mask_image(&self, mask: &Image) -> Result<()> {
+        if mask.bits_per_component()? == 1 {
+            let status = unsafe {
+                libharu_sys::HPDF_Image_SetMaskImage(self.handle(), mask.handle())
+            };
+
+            if status != 0 {
+                return Err(self.doc.status_error("HPDF_Image_SetMaskImage", status));
+            }
+        } else {
+            let status = unsafe {
+                libharu_sys::HPDF_Image_AddSMask(self.handle(), mask.handle())
+            };
+
+            if status != 0 {
+                return Err(self.doc.status_error("HPDF_Image_AddSMask", status));
+            }
+        }
+
+        Ok(())
+    }
+}
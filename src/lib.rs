@@ -1,302 +1,716 @@
-//! Rust binding of libharu PDF library.
-#![warn(missing_docs)]
-
-use std::fmt::Debug;
-
-use libharu_sys::{HPDF_Point, HPDF_Rect};
-mod context;
-mod destination;
-mod document;
-mod encoder;
-mod error;
-mod image;
-mod outline;
-mod page;
-
-/// prelude
-pub mod prelude;
-
-/// Floating-point type used in libharu.
-pub type Real = libharu_sys::HPDF_REAL;
-
-/// A lower-level representation of the rectangle type used in libharu
-/// Translates to `/Rect [ xLL yLL xUR yUR ]`
-#[derive(Default)]
-pub struct Rectangle {
-    pub lower_left: Point,
-    pub upper_right: Point,
-}
-
-enum Corner {
-    LowerLeft,
-    LowerRight,
-    UpperLeft,
-    UpperRight,
-}
-
-pub struct RectangleBuilder {
-    point: Point,
-    starting: Corner,
-    height: f32,
-    width: f32,
-}
-
-impl RectangleBuilder {
-    /// Define a starting point for the Rectangle
-    fn new(point: Point) -> Self {
-        Self {
-            point: Point::default(),
-            starting: Corner::LowerLeft,
-            height: 0.0,
-            width: 0.0,
-        }
-    }
-    /// Designate the starting corner
-    fn starting_corner(&mut self, corner: Corner) -> &mut Self {
-        self.starting = corner;
-        self
-    }
-
-    /// Set the width of the rectangle
-    fn set_width(&mut self, width: f32) -> &mut Self {
-        self.width = width;
-        self
-    }
-
-    /// Set the height of the rectangle
-    fn set_height(&mut self, height: f32) -> &mut Self {
-        self.height = height;
-        self
-    }
-
-    /// Build and return the rectangle
-    fn build(self) -> Rectangle {
-        match self.starting {
-            Corner::LowerLeft => Rectangle {
-                lower_left: self.point,
-                upper_right: Point {
-                    x: self.point.x + self.width,
-                    y: self.point.y + self.height,
-                },
-            },
-            Corner::LowerRight => Rectangle {
-                lower_left: Point {
-                    x: self.point.x - self.width,
-                    y: self.point.y,
-                },
-                upper_right: Point {
-                    x: self.point.x,
-                    y: self.point.y + self.height,
-                },
-            },
-            Corner::UpperLeft => Rectangle {
-                lower_left: Point {
-                    x: self.point.x,
-                    y: self.point.y - self.height,
-                },
-                upper_right: Point {
-                    x: self.point.x + self.width,
-                    y: self.point.y,
-                },
-            },
-            Corner::UpperRight => Rectangle {
-                lower_left: Point {
-                    x: self.point.x - self.width,
-                    y: self.point.y - self.height,
-                },
-                upper_right: self.point,
-            },
-        }
-    }
-}
-
-impl From<Rectangle> for HPDF_Rect {
-    fn from(value: Rectangle) -> Self {
-        Self {
-            left: value.lower_left.x,
-            bottom: value.lower_left.y,
-            right: value.upper_right.x,
-            top: value.upper_right.y,
-        }
-    }
-}
-
-/// RGB color type.
-#[derive(Debug, Clone)]
-pub struct Color {
-    /// red (0.0 ~ 1.0)
-    pub red: Real,
-
-    /// green (0.0 ~ 1.0)
-    pub green: Real,
-
-    /// blue (0.0 ~ 1.0)
-    pub blue: Real,
-}
-
-impl Copy for Color {}
-
-impl Default for Color {
-    fn default() -> Self {
-        Self {
-            red: 0.0,
-            green: 0.0,
-            blue: 0.0
-        }
-    }
-}
-
-impl From<(Real, Real, Real)> for Color {
-    fn from(v: (Real, Real, Real)) -> Self {
-        Self {
-            red: v.0,
-            green: v.1,
-            blue: v.2,
-        }
-    }
-}
-
-/// CMYK color type
-#[derive(Debug, Clone)]
-pub struct CmykColor {
-    /// cyan (0.0 ~ 1.0)
-    pub cyan: Real,
-
-    /// magenta (0.0 ~ 1.0)
-    pub magenta: Real,
-
-    /// yellow (0.0 ~ 1.0)
-    pub yellow: Real,
-
-    /// keyplate (0.0 ~ 1.0)
-    pub keyplate: Real,
-}
-
-impl Copy for CmykColor {}
-
-impl From<(Real, Real, Real, Real)> for CmykColor {
-    fn from(v: (Real, Real, Real, Real)) -> Self {
-        Self {
-            cyan: v.0,
-            magenta: v.1,
-            yellow: v.2,
-            keyplate: v.3,
-        }
-    }
-}
-/// Point
-#[derive(Debug, Clone, Default)]
-pub struct Point {
-    /// x
-    pub x: Real,
-
-    /// y
-    pub y: Real,
-}
-
-impl From<HPDF_Point> for Point {
-    fn from(v: HPDF_Point) -> Self {
-        Self { x: v.x, y: v.y }
-    }
-}
-
-impl Copy for Point {}
-
-impl From<(Real, Real)> for Point {
-    fn from(v: (Real, Real)) -> Self {
-        Self { x: v.0, y: v.1 }
-    }
-}
-
-impl Point {
-    pub fn set<P>(&mut self, point: P) where P: Into<Point> {
-        let point = point.into();
-        self.x = point.x;
-        self.y = point.y;
-    }
-}
-
-/// Rect
-#[derive(Debug, Clone)]
-pub struct Rect {
-    /// Left position
-    pub left: Real,
-
-    /// Top position
-    pub top: Real,
-
-    /// Right position
-    pub right: Real,
-
-    /// Bottom position
-    pub bottom: Real,
-}
-
-impl From<HPDF_Rect> for Rect {
-    fn from(v: HPDF_Rect) -> Self {
-        Self {
-            left: v.left,
-            top: v.top,
-            right: v.right,
-            bottom: v.bottom,
-        }
-    }
-}
-
-impl Copy for Rect {}
-
-impl From<(Real, Real, Real, Real)> for Rect {
-    fn from(v: (Real, Real, Real, Real)) -> Self {
-        Self {
-            left: v.0,
-            top: v.1,
-            right: v.2,
-            bottom: v.3,
-        }
-    }
-}
-
-impl From<(Real, Real)> for Rect {
-    fn from(v: (Real, Real)) -> Self {
-        Self {
-            left: v.0,
-            top: v.1,
-            right: v.0,
-            bottom: v.1,
-        }
-    }
-}
-
-/// Font handle type.
-pub struct Font<'a> {
-    font: libharu_sys::HPDF_Font,
-    _doc: &'a prelude::Document,
-}
-
-impl<'a> Font<'a> {
-    pub(crate) fn new(_doc: &'a prelude::Document, font: libharu_sys::HPDF_Font) -> Self {
-        Self { font, _doc }
-    }
-
-    #[inline]
-    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Font {
-        self.font
-    }
-
-    /// Get the name of the font.
-    pub fn name(&self) -> anyhow::Result<&str> {
-        unsafe {
-            let name = libharu_sys::HPDF_Font_GetFontName(self.handle());
-
-            let s = std::ffi::CStr::from_ptr(name).to_str()?;
-
-            Ok(s)
-        }
-    }
-}
-
-impl<'a> Debug for Font<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Font").field("font", &self.font).finish()
-    }
-}
+//! Rust binding of libharu PDF library.
+#![warn(missing_docs)]
+
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use libharu_sys::{HPDF_Date, HPDF_Point, HPDF_Rect, HPDF_TextWidth, HPDF_TransMatrix};
+mod annotation;
+mod content_stream;
+mod context;
+mod destination;
+mod document;
+mod encoder;
+mod error;
+mod ext_gstate;
+mod fallback;
+mod image;
+mod outline;
+mod page;
+mod shading;
+mod stream;
+#[cfg(feature = "svg")]
+mod svg;
+mod svg_path;
+mod xobject;
+
+/// prelude
+pub mod prelude;
+
+pub use error::{Error, ErrorKind};
+
+/// Result type returned from the functions of this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Floating-point type used in libharu.
+pub type Real = libharu_sys::HPDF_REAL;
+
+/// A lower-level representation of the rectangle type used in libharu
+/// Translates to `/Rect [ xLL yLL xUR yUR ]`
+#[derive(Default)]
+pub struct Rectangle {
+    pub lower_left: Point,
+    pub upper_right: Point,
+}
+
+enum Corner {
+    LowerLeft,
+    LowerRight,
+    UpperLeft,
+    UpperRight,
+}
+
+pub struct RectangleBuilder {
+    point: Point,
+    starting: Corner,
+    height: f32,
+    width: f32,
+}
+
+impl RectangleBuilder {
+    /// Define a starting point for the Rectangle
+    fn new(point: Point) -> Self {
+        Self {
+            point: Point::default(),
+            starting: Corner::LowerLeft,
+            height: 0.0,
+            width: 0.0,
+        }
+    }
+    /// Designate the starting corner
+    fn starting_corner(&mut self, corner: Corner) -> &mut Self {
+        self.starting = corner;
+        self
+    }
+
+    /// Set the width of the rectangle
+    fn set_width(&mut self, width: f32) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the height of the rectangle
+    fn set_height(&mut self, height: f32) -> &mut Self {
+        self.height = height;
+        self
+    }
+
+    /// Build and return the rectangle
+    fn build(self) -> Rectangle {
+        match self.starting {
+            Corner::LowerLeft => Rectangle {
+                lower_left: self.point,
+                upper_right: Point {
+                    x: self.point.x + self.width,
+                    y: self.point.y + self.height,
+                },
+            },
+            Corner::LowerRight => Rectangle {
+                lower_left: Point {
+                    x: self.point.x - self.width,
+                    y: self.point.y,
+                },
+                upper_right: Point {
+                    x: self.point.x,
+                    y: self.point.y + self.height,
+                },
+            },
+            Corner::UpperLeft => Rectangle {
+                lower_left: Point {
+                    x: self.point.x,
+                    y: self.point.y - self.height,
+                },
+                upper_right: Point {
+                    x: self.point.x + self.width,
+                    y: self.point.y,
+                },
+            },
+            Corner::UpperRight => Rectangle {
+                lower_left: Point {
+                    x: self.point.x - self.width,
+                    y: self.point.y - self.height,
+                },
+                upper_right: self.point,
+            },
+        }
+    }
+}
+
+impl From<Rectangle> for HPDF_Rect {
+    fn from(value: Rectangle) -> Self {
+        Self {
+            left: value.lower_left.x,
+            bottom: value.lower_left.y,
+            right: value.upper_right.x,
+            top: value.upper_right.y,
+        }
+    }
+}
+
+/// RGB color type.
+#[derive(Debug, Clone)]
+pub struct Color {
+    /// red (0.0 ~ 1.0)
+    pub red: Real,
+
+    /// green (0.0 ~ 1.0)
+    pub green: Real,
+
+    /// blue (0.0 ~ 1.0)
+    pub blue: Real,
+}
+
+impl Copy for Color {}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self {
+            red: 0.0,
+            green: 0.0,
+            blue: 0.0
+        }
+    }
+}
+
+impl From<(Real, Real, Real)> for Color {
+    fn from(v: (Real, Real, Real)) -> Self {
+        Self {
+            red: v.0,
+            green: v.1,
+            blue: v.2,
+        }
+    }
+}
+
+/// CMYK color type
+#[derive(Debug, Clone)]
+pub struct CmykColor {
+    /// cyan (0.0 ~ 1.0)
+    pub cyan: Real,
+
+    /// magenta (0.0 ~ 1.0)
+    pub magenta: Real,
+
+    /// yellow (0.0 ~ 1.0)
+    pub yellow: Real,
+
+    /// keyplate (0.0 ~ 1.0)
+    pub keyplate: Real,
+}
+
+impl Copy for CmykColor {}
+
+impl From<(Real, Real, Real, Real)> for CmykColor {
+    fn from(v: (Real, Real, Real, Real)) -> Self {
+        Self {
+            cyan: v.0,
+            magenta: v.1,
+            yellow: v.2,
+            keyplate: v.3,
+        }
+    }
+}
+/// Point
+#[derive(Debug, Clone, Default)]
+pub struct Point {
+    /// x
+    pub x: Real,
+
+    /// y
+    pub y: Real,
+}
+
+impl From<HPDF_Point> for Point {
+    fn from(v: HPDF_Point) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl Copy for Point {}
+
+impl From<Point> for HPDF_Point {
+    fn from(v: Point) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl From<(Real, Real)> for Point {
+    fn from(v: (Real, Real)) -> Self {
+        Self { x: v.0, y: v.1 }
+    }
+}
+
+impl Point {
+    pub fn set<P>(&mut self, point: P) where P: Into<Point> {
+        let point = point.into();
+        self.x = point.x;
+        self.y = point.y;
+    }
+}
+
+/// Rect
+#[derive(Debug, Clone)]
+pub struct Rect {
+    /// Left position
+    pub left: Real,
+
+    /// Top position
+    pub top: Real,
+
+    /// Right position
+    pub right: Real,
+
+    /// Bottom position
+    pub bottom: Real,
+}
+
+impl From<HPDF_Rect> for Rect {
+    fn from(v: HPDF_Rect) -> Self {
+        Self {
+            left: v.left,
+            top: v.top,
+            right: v.right,
+            bottom: v.bottom,
+        }
+    }
+}
+
+impl Rect {
+    /* the same rectangle with `left <= right` and `bottom <= top`. */
+    pub(crate) fn normalized(self) -> Self {
+        Self {
+            left: self.left.min(self.right),
+            top: self.top.max(self.bottom),
+            right: self.left.max(self.right),
+            bottom: self.top.min(self.bottom),
+        }
+    }
+}
+
+impl Copy for Rect {}
+
+impl From<Rect> for HPDF_Rect {
+    fn from(v: Rect) -> Self {
+        Self {
+            left: v.left,
+            top: v.top,
+            right: v.right,
+            bottom: v.bottom,
+        }
+    }
+}
+
+impl From<(Real, Real, Real, Real)> for Rect {
+    fn from(v: (Real, Real, Real, Real)) -> Self {
+        Self {
+            left: v.0,
+            top: v.1,
+            right: v.2,
+            bottom: v.3,
+        }
+    }
+}
+
+impl From<(Real, Real)> for Rect {
+    fn from(v: (Real, Real)) -> Self {
+        Self {
+            left: v.0,
+            top: v.1,
+            right: v.0,
+            bottom: v.1,
+        }
+    }
+}
+
+/// Transformation matrix `[a b c d x y]`, mapping `(px, py)` to
+/// `(a * px + c * py + x, b * px + d * py + y)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    /// a
+    pub a: Real,
+
+    /// b
+    pub b: Real,
+
+    /// c
+    pub c: Real,
+
+    /// d
+    pub d: Real,
+
+    /// x
+    pub x: Real,
+
+    /// y
+    pub y: Real,
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<HPDF_TransMatrix> for Matrix {
+    fn from(v: HPDF_TransMatrix) -> Self {
+        Self {
+            a: v.a,
+            b: v.b,
+            c: v.c,
+            d: v.d,
+            x: v.x,
+            y: v.y,
+        }
+    }
+}
+
+impl From<Matrix> for HPDF_TransMatrix {
+    fn from(v: Matrix) -> Self {
+        Self {
+            a: v.a,
+            b: v.b,
+            c: v.c,
+            d: v.d,
+            x: v.x,
+            y: v.y,
+        }
+    }
+}
+
+impl Matrix {
+    /// Matrix which leaves every point unchanged.
+    pub fn identity() -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            x: 0.0,
+            y: 0.0,
+        }
+    }
+
+    /// Matrix moving the origin to `(tx, ty)`.
+    pub fn translate(tx: Real, ty: Real) -> Self {
+        Self {
+            x: tx,
+            y: ty,
+            ..Self::identity()
+        }
+    }
+
+    /// Matrix rotating counterclockwise by `angle` degrees around the origin.
+    pub fn rotate(angle: Real) -> Self {
+        let (sin, cos) = angle.to_radians().sin_cos();
+
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            ..Self::identity()
+        }
+    }
+
+    /// Matrix scaling by `sx` horizontally and `sy` vertically.
+    pub fn scale(sx: Real, sy: Real) -> Self {
+        Self {
+            a: sx,
+            d: sy,
+            ..Self::identity()
+        }
+    }
+
+    /// Matrix skewing the x axis by `alpha` degrees and the y axis by `beta` degrees.
+    pub fn skew(alpha: Real, beta: Real) -> Self {
+        Self {
+            b: alpha.to_radians().tan(),
+            c: beta.to_radians().tan(),
+            ..Self::identity()
+        }
+    }
+
+    /// Matrix flipping a y-down coordinate system, such as SVG's, to page space,
+    /// with its origin at `(0, top)` of the page.
+    pub fn flip_y(top: Real) -> Self {
+        Self {
+            d: -1.0,
+            y: top,
+            ..Self::identity()
+        }
+    }
+
+    /// Get the matrix applying `self` first, then `other`.
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        Matrix {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            x: self.x * other.a + self.y * other.c + other.x,
+            y: self.x * other.b + self.y * other.d + other.y,
+        }
+    }
+
+    /// Get the inverse matrix, or `None` if the matrix is not invertible.
+    pub fn invert(&self) -> Option<Matrix> {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        Some(Matrix {
+            a: self.d / det,
+            b: -self.b / det,
+            c: -self.c / det,
+            d: self.a / det,
+            x: (self.c * self.y - self.d * self.x) / det,
+            y: (self.b * self.x - self.a * self.y) / det,
+        })
+    }
+
+    /// Transform a point.
+    pub fn transform_point<P>(&self, point: P) -> Point
+    where
+        P: Into<Point>,
+    {
+        let p = point.into();
+
+        Point {
+            x: self.a * p.x + self.c * p.y + self.x,
+            y: self.b * p.x + self.d * p.y + self.y,
+        }
+    }
+
+    /// Transform a rectangle, returning the bounding box of its transformed corners.
+    pub fn transform_rect<R>(&self, rect: R) -> Rect
+    where
+        R: Into<Rect>,
+    {
+        let r = rect.into();
+        let corners = [
+            self.transform_point((r.left, r.bottom)),
+            self.transform_point((r.right, r.bottom)),
+            self.transform_point((r.right, r.top)),
+            self.transform_point((r.left, r.top)),
+        ];
+
+        let mut ret = Rect {
+            left: corners[0].x,
+            top: corners[0].y,
+            right: corners[0].x,
+            bottom: corners[0].y,
+        };
+        for p in &corners[1..] {
+            ret.left = ret.left.min(p.x);
+            ret.right = ret.right.max(p.x);
+            ret.bottom = ret.bottom.min(p.y);
+            ret.top = ret.top.max(p.y);
+        }
+
+        ret
+    }
+}
+
+/// Date and time used in annotations.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Date {
+    /// Year
+    pub year: i32,
+
+    /// Month (1 ~ 12)
+    pub month: i32,
+
+    /// Day (1 ~ 31)
+    pub day: i32,
+
+    /// Hour (0 ~ 23)
+    pub hour: i32,
+
+    /// Minutes (0 ~ 59)
+    pub minutes: i32,
+
+    /// Seconds (0 ~ 59)
+    pub seconds: i32,
+
+    /// Offset from UTC in minutes
+    pub utc_offset: i32,
+}
+
+impl From<Date> for HPDF_Date {
+    fn from(v: Date) -> Self {
+        let ind = match v.utc_offset {
+            0 => b'Z',
+            offset if offset > 0 => b'+',
+            _ => b'-',
+        };
+        let offset = v.utc_offset.abs();
+
+        Self {
+            year: v.year,
+            month: v.month,
+            day: v.day,
+            hour: v.hour,
+            minutes: v.minutes,
+            seconds: v.seconds,
+            ind: ind as std::os::raw::c_char,
+            off_hour: offset / 60,
+            off_minutes: offset % 60,
+        }
+    }
+}
+
+/// Width of a text measured with `Font::text_width`. (in font units)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextWidth {
+    /// Number of characters
+    pub numchars: u32,
+
+    /// Number of words
+    pub numwords: u32,
+
+    /// Width of the text in 1/1000 of the font size
+    pub width: u32,
+
+    /// Number of spaces
+    pub numspace: u32,
+}
+
+impl From<HPDF_TextWidth> for TextWidth {
+    fn from(v: HPDF_TextWidth) -> Self {
+        Self {
+            numchars: v.numchars,
+            numwords: v.numwords,
+            width: v.width,
+            numspace: v.numspace,
+        }
+    }
+}
+
+impl TextWidth {
+    /// Get the width of the text at the given font size.
+    pub fn width_at(&self, font_size: Real) -> Real {
+        Font::units_to_points(self.width as Real, font_size)
+    }
+}
+
+/// Shorten `len` to the nearest UTF-8 character boundary of `text`.
+pub(crate) fn char_boundary(text: &str, len: usize) -> usize {
+    (0..=len).rev().find(|i| text.is_char_boundary(*i)).unwrap_or(0)
+}
+
+/// Font handle type.
+pub struct Font<'a> {
+    font: libharu_sys::HPDF_Font,
+    _doc: &'a prelude::Document,
+}
+
+impl<'a> Font<'a> {
+    pub(crate) fn new(_doc: &'a prelude::Document, font: libharu_sys::HPDF_Font) -> Self {
+        Self { font, _doc }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Font {
+        self.font
+    }
+
+    /// Get the name of the font.
+    pub fn name(&self) -> Result<&str> {
+        unsafe {
+            let name = libharu_sys::HPDF_Font_GetFontName(self.handle());
+
+            let s = std::ffi::CStr::from_ptr(name).to_str()?;
+
+            Ok(s)
+        }
+    }
+
+    /// Convert a value in font units (1/1000 of the font size) to points at the given font size.
+    pub fn units_to_points(units: Real, font_size: Real) -> Real {
+        units * font_size / 1000.0
+    }
+
+    /// Get the bounding box of the font. (in font units)
+    pub fn bbox(&self) -> Result<Rect> {
+        let ret = unsafe { libharu_sys::HPDF_Font_GetBBox(self.handle()) };
+
+        Ok(ret.into())
+    }
+
+    /// Get the vertical ascent of the font. (in font units)
+    pub fn ascent(&self) -> Result<i32> {
+        let ret = unsafe { libharu_sys::HPDF_Font_GetAscent(self.handle()) };
+
+        Ok(ret)
+    }
+
+    /// Get the vertical descent of the font. (in font units)
+    pub fn descent(&self) -> Result<i32> {
+        let ret = unsafe { libharu_sys::HPDF_Font_GetDescent(self.handle()) };
+
+        Ok(ret)
+    }
+
+    /// Get the distance from the baseline of lowercase letters. (in font units)
+    pub fn x_height(&self) -> Result<u32> {
+        let ret = unsafe { libharu_sys::HPDF_Font_GetXHeight(self.handle()) };
+
+        Ok(ret)
+    }
+
+    /// Get the distance from the baseline of uppercase letters. (in font units)
+    pub fn cap_height(&self) -> Result<u32> {
+        let ret = unsafe { libharu_sys::HPDF_Font_GetCapHeight(self.handle()) };
+
+        Ok(ret)
+    }
+
+    /// Get the width of a unicode character. (in font units)
+    pub fn unicode_width(&self, code: u16) -> Result<i32> {
+        let ret = unsafe { libharu_sys::HPDF_Font_GetUnicodeWidth(self.handle(), code) };
+
+        Ok(ret)
+    }
+
+    /// Get the total width of the text, number of characters, and number of words.
+    pub fn text_width(&self, text: &str) -> Result<TextWidth> {
+        let len = u32::try_from(text.len())?;
+        let ret = unsafe { libharu_sys::HPDF_Font_TextWidth(self.handle(), text.as_ptr(), len) };
+
+        Ok(ret.into())
+    }
+
+    /// Calculate the byte length which can be included within the specified width.
+    ///
+    /// Unlike `Page::measure_text`, the font size and spacing are given explicitly,
+    /// so no page is required.
+    pub fn measure_text(
+        &self,
+        text: &str,
+        width: Real,
+        font_size: Real,
+        char_space: Real,
+        word_space: Real,
+        wordwrap: bool,
+    ) -> Result<(usize, Real)> {
+        let len = u32::try_from(text.len())?;
+        let wordwrap = match wordwrap {
+            true => 1,
+            false => 0,
+        };
+
+        let mut real_width = 0.0;
+        let ret = unsafe {
+            libharu_sys::HPDF_Font_MeasureText(
+                self.handle(),
+                text.as_ptr(),
+                len,
+                width,
+                font_size,
+                char_space,
+                word_space,
+                wordwrap,
+                &mut real_width,
+            )
+        };
+
+        Ok((char_boundary(text, ret as usize), real_width))
+    }
+}
+
+impl<'a> Debug for Font<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Font").field("font", &self.font).finish()
+    }
+}
@@ -1,52 +1,173 @@
-use crate::document::Document;
-use crate::destination::Destination;
-
-/// Outline handle type.
-pub struct Outline<'a> {
-    outline: libharu_sys::HPDF_Outline,
-    _doc: &'a Document,
-}
-
-impl<'a> Outline<'a> {
-    pub(crate) fn new(_doc: &'a Document, outline: libharu_sys::HPDF_Outline) -> Self {
-        Self { _doc, outline }
-    }
-
-    #[inline]
-    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Outline {
-        self.outline
-    }
-
-    /// Set whether this node is opened or not when the outline is displayed for the first time.
-//    #[must_use]
-    pub fn set_opened(&self, opened: bool) -> anyhow::Result<()> {
-        let opened = match opened {
-            true => libharu_sys::HPDF_TRUE,
-            false => libharu_sys::HPDF_FALSE,
-        };
-
-        let status = unsafe {
-            libharu_sys::HPDF_Outline_SetOpened(self.handle(), opened)
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Outline_SetOpened failed (status={})", status);
-        }
-
-        Ok(())
-    }
-    
-    /// Set a destination object which becomes to a target to jump when the outline is clicked.
-//    #[must_use]
-    pub fn set_destination(&self, dst: &Destination) -> anyhow::Result<()> {
-        let status = unsafe {
-            libharu_sys::HPDF_Outline_SetDestination(self.handle(), dst.handle())
-        };
-
-        if status != 0 {
-            anyhow::bail!("HPDF_Outline_SetDestination failed (status={})", status);
-        }
-
-        Ok(())
-    }
-}
+use crate::document::Document;
+use crate::destination::{Destination, DestinationView};
+use crate::page::Page;
+use crate::{Real, Result};
+
+/// Outline handle type.
+///
+/// Title colors and bold/italic styles can not be set: libharu has no public
+/// function writing the `/C` and `/F` entries of an outline item.
+pub struct Outline<'a> {
+    outline: libharu_sys::HPDF_Outline,
+    doc: &'a Document,
+}
+
+impl<'a> Outline<'a> {
+    pub(crate) fn new(doc: &'a Document, outline: libharu_sys::HPDF_Outline) -> Self {
+        Self { doc, outline }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Outline {
+        self.outline
+    }
+
+    /// Set whether this node is opened or not when the outline is displayed for the first time.
+//    #[must_use]
+    pub fn set_opened(&self, opened: bool) -> Result<()> {
+        let opened = match opened {
+            true => libharu_sys::HPDF_TRUE,
+            false => libharu_sys::HPDF_FALSE,
+        };
+
+        let status = unsafe {
+            libharu_sys::HPDF_Outline_SetOpened(self.handle(), opened)
+        };
+
+        if status != 0 {
+            return Err(self.doc.status_error("HPDF_Outline_SetOpened", status));
+        }
+
+        Ok(())
+    }
+    
+    /// Set a destination object which becomes to a target to jump when the outline is clicked.
+//    #[must_use]
+    pub fn set_destination(&self, dst: &Destination) -> Result<()> {
+        let status = unsafe {
+            libharu_sys::HPDF_Outline_SetDestination(self.handle(), dst.handle())
+        };
+
+        if status != 0 {
+            return Err(self.doc.status_error("HPDF_Outline_SetDestination", status));
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder of an outline tree.
+///
+/// Each builder stands for a node of the tree (or the root of the outline)
+/// and adds child nodes under it.
+pub struct OutlineBuilder<'a> {
+    doc: &'a Document,
+    node: Option<Outline<'a>>,
+}
+
+impl<'a> OutlineBuilder<'a> {
+    pub(crate) fn new(doc: &'a Document) -> Self {
+        Self { doc, node: None }
+    }
+
+    /// Get the outline node of this builder. (`None` for the root)
+    pub fn outline(&self) -> Option<&Outline<'a>> {
+        self.node.as_ref()
+    }
+
+    /// Add a child node and get the builder of the child.
+    ///
+    /// The node jumps to the page displayed with the view if a target is given.
+    /// Titles which are not ASCII are written with the "UTF-8" encoder.
+    pub fn add_child(
+        &self,
+        title: &str,
+        target: Option<(&Page, DestinationView)>,
+        opened: bool,
+    ) -> Result<OutlineBuilder<'a>> {
+        let outline = if title.is_ascii() {
+            self.doc.create_outline(title, self.node.as_ref(), None)?
+        } else {
+            let enc = self.doc.utf8_encoder()?;
+            self.doc.create_outline(title, self.node.as_ref(), Some(&enc))?
+        };
+
+        if let Some((page, view)) = target {
+            let dst = page.create_destination_with(view)?;
+            outline.set_destination(&dst)?;
+        }
+        outline.set_opened(opened)?;
+
+        Ok(OutlineBuilder {
+            doc: self.doc,
+            node: Some(outline),
+        })
+    }
+
+    /// Add nodes for the headings of a document.
+    ///
+    /// Each heading is `(level, title, page, y)`, where level 0 is a child of this builder
+    /// and `y` is the top of the page area displayed when the node is clicked.
+    pub fn add_headings(&self, headings: &[(usize, &str, &Page, Real)]) -> Result<()> {
+        let parents = heading_parents(headings.iter().map(|heading| heading.0));
+        let mut nodes: Vec<OutlineBuilder<'a>> = Vec::with_capacity(headings.len());
+
+        for (&(_, title, page, y), parent) in headings.iter().zip(parents) {
+            let view = DestinationView::XYZ {
+                left: 0.0,
+                top: y,
+                zoom: 0.0,
+            };
+            let parent = match parent {
+                Some(index) => &nodes[index],
+                None => self,
+            };
+            let child = parent.add_child(title, Some((page, view)), false)?;
+
+            nodes.push(child);
+        }
+
+        Ok(())
+    }
+}
+
+/* index of the parent of each heading, the closest previous one with a lower level. */
+fn heading_parents<I>(levels: I) -> Vec<Option<usize>>
+where
+    I: Iterator<Item = usize>,
+{
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+
+    levels
+        .enumerate()
+        .map(|(index, level)| {
+            while stack.last().map_or(false, |&(top, _)| top >= level) {
+                stack.pop();
+            }
+            let parent = stack.last().map(|&(_, parent)| parent);
+            stack.push((level, index));
+
+            parent
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::heading_parents;
+
+    #[test]
+    fn skipped_levels_keep_siblings() {
+        let parents = heading_parents(vec![0, 2, 2, 1, 0, 1].into_iter());
+        assert_eq!(
+            parents,
+            vec![None, Some(0), Some(0), Some(0), None, Some(4)]
+        );
+    }
+
+    #[test]
+    fn headings_below_the_first_level() {
+        let parents = heading_parents(vec![1, 0, 3, 2, 3].into_iter());
+        assert_eq!(parents, vec![None, None, Some(1), Some(1), Some(3)]);
+    }
+}
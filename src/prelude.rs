@@ -1,22 +1,45 @@
-pub use crate::document::{
-    CompressionMode, Document, Metadata, PageBuilder, PageMode, PageNumStyle,
-};
-
-pub use crate::page::{
-    BorderStyle, HighlightMode, LineCap, LineJoin, LinkBorder, Page, PageDirection, PageSize,
-    TextAlignment, TextRenderingMode,
-};
-
-pub use crate::outline::Outline;
-
-pub use crate::destination::Destination;
-
-pub use crate::image::Image;
-
-pub use crate::context::{
-    PageDescPathCommonFunction, PageDescTeextCommonFunction, PageDescriptionMode, PagePathMode,
-    PageTextMode,
-};
-
-pub use crate::Rectangle;
-pub use crate::{CmykColor, Color, Font, Point, Real, Rect};
+pub use crate::document::{
+    CompressionMode, Document, EncryptionMode, Metadata, PageBuilder, PageMode, PageNumStyle,
+    Permissions,
+};
+
+pub use crate::page::{
+    FillRule, GMode, LineCap, LineJoin, Page, PageDirection, PageSize, TextAlignment,
+    TextRenderingMode,
+};
+
+pub use crate::annotation::{
+    AnnotIcon, FreeTextAnnotation, HighlightMode, LineAnnotation, LineEnding, LinkAnnotation,
+    LinkBorder, MarkupAnnotation, PopupAnnotation, Quad, ShapeAnnotation, TextAnnotation,
+    TextMarkup, TextMarkupAnnotation,
+};
+
+pub use crate::outline::{Outline, OutlineBuilder};
+
+pub use crate::destination::{Destination, DestinationView};
+
+pub use crate::ext_gstate::{BlendMode, ExtGState};
+
+pub use crate::fallback::{CharCoverage, FontFallback};
+
+pub use crate::image::{ColorSpace, Image};
+
+pub use crate::shading::{Gradient, GradientShape, Shading, ShadingColor};
+
+pub use crate::stream::DocumentReader;
+
+#[cfg(feature = "svg")]
+pub use crate::svg::SvgWarning;
+
+pub use crate::xobject::XObject;
+
+pub use crate::content_stream::ContentStream;
+
+pub use crate::context::{
+    GStateGuard, PageDescPathCommonFunction, PageDescTeextCommonFunction, PageDescriptionMode,
+    PagePathMode, PageTextMode,
+};
+
+pub use crate::Rectangle;
+pub use crate::{Error, ErrorKind};
+pub use crate::{CmykColor, Color, Date, Font, Matrix, Point, Real, Rect, TextWidth};
@@ -0,0 +1,614 @@
+use crate::document::Document;
+use crate::error::ErrorKind;
+use crate::{CmykColor, Color, Point, Real, Rect, Result};
+
+/* number of segments a circle of a radial gradient is divided into. */
+const RADIAL_SEGMENTS: usize = 64;
+
+/* limit of the search for how far an extended radial gradient must reach. */
+const MAX_EXTEND_STEPS: usize = 24;
+
+type Rgb = (Real, Real, Real);
+
+/// Color of a gradient stop.
+///
+/// libharu only supports shadings in DeviceRGB, so gray is converted exactly
+/// and CMYK with the naive `(1 - c) * (1 - k)` formula.
+#[derive(Debug, Clone, Copy)]
+pub enum ShadingColor {
+    /// Gray level (0.0 ~ 1.0)
+    Gray(Real),
+
+    /// RGB color
+    Rgb(Color),
+
+    /// CMYK color
+    Cmyk(CmykColor),
+}
+
+impl From<Color> for ShadingColor {
+    fn from(v: Color) -> Self {
+        ShadingColor::Rgb(v)
+    }
+}
+
+impl From<CmykColor> for ShadingColor {
+    fn from(v: CmykColor) -> Self {
+        ShadingColor::Cmyk(v)
+    }
+}
+
+impl ShadingColor {
+    fn to_rgb(self) -> Rgb {
+        match self {
+            ShadingColor::Gray(gray) => (gray, gray, gray),
+            ShadingColor::Rgb(color) => (color.red, color.green, color.blue),
+            ShadingColor::Cmyk(color) => {
+                let k = 1.0 - color.black;
+                (
+                    (1.0 - color.cyan) * k,
+                    (1.0 - color.magenta) * k,
+                    (1.0 - color.yellow) * k,
+                )
+            }
+        }
+    }
+}
+
+/// Geometry of a gradient.
+#[derive(Debug, Clone, Copy)]
+pub enum GradientShape {
+    /// Color varies along the line from `start` (offset 0.0) to `end` (offset 1.0).
+    Axial {
+        /// Start point
+        start: Point,
+
+        /// End point
+        end: Point,
+    },
+
+    /// Color varies between the start circle (offset 0.0) and the end circle (offset 1.0).
+    Radial {
+        /// Center of the start circle
+        start: Point,
+
+        /// Radius of the start circle
+        start_radius: Real,
+
+        /// Center of the end circle
+        end: Point,
+
+        /// Radius of the end circle
+        end_radius: Real,
+    },
+}
+
+/// Description of an axial or radial gradient, used to create a `Shading`.
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    shape: GradientShape,
+    stops: Vec<(Real, Rgb)>,
+    extend: (bool, bool),
+}
+
+impl Gradient {
+    /// Create an axial gradient from `start` to `end`.
+    pub fn axial<P1, P2>(start: P1, end: P2) -> Self
+    where
+        P1: Into<Point>,
+        P2: Into<Point>,
+    {
+        Self::new(GradientShape::Axial {
+            start: start.into(),
+            end: end.into(),
+        })
+    }
+
+    /// Create a radial gradient from the circle around `start` to the circle around `end`.
+    pub fn radial<P1, P2>(start: P1, start_radius: Real, end: P2, end_radius: Real) -> Self
+    where
+        P1: Into<Point>,
+        P2: Into<Point>,
+    {
+        Self::new(GradientShape::Radial {
+            start: start.into(),
+            start_radius,
+            end: end.into(),
+            end_radius,
+        })
+    }
+
+    fn new(shape: GradientShape) -> Self {
+        Self {
+            shape,
+            stops: Vec::new(),
+            extend: (false, false),
+        }
+    }
+
+    /// Add a color stop at `offset` (0.0 ~ 1.0).
+    ///
+    /// The color is converted to RGB, a CMYK color with `(1 - c) * (1 - k)` for red and
+    /// likewise for green and blue, without any color management.
+    pub fn add_stop<C>(mut self, offset: Real, color: C) -> Self
+    where
+        C: Into<ShadingColor>,
+    {
+        self.stops.push((offset, color.into().to_rgb()));
+        self
+    }
+
+    /// Set whether the gradient is extended beyond its start and end with the first and last color.
+    pub fn extend(mut self, start: bool, end: bool) -> Self {
+        self.extend = (start, end);
+        self
+    }
+
+    /* split into triangles with a color at each vertex, covering `bbox` (normalized) as far as extended. */
+    pub(crate) fn triangles(&self, bbox: Rect) -> Result<Vec<[(Point, Rgb); 3]>> {
+        let mut stops = self.stops.clone();
+        if stops.is_empty() {
+            return Err(ErrorKind::InvalidParameter.into());
+        }
+        for stop in &mut stops {
+            stop.0 = stop.0.max(0.0).min(1.0);
+        }
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        match self.shape {
+            GradientShape::Axial { start, end } => self.axial_triangles(&stops, start, end, bbox),
+            GradientShape::Radial {
+                start,
+                start_radius,
+                end,
+                end_radius,
+            } => {
+                let circles = RadialCircles {
+                    start,
+                    start_radius,
+                    end,
+                    end_radius,
+                };
+                self.radial_triangles(&stops, &circles, bbox)
+            }
+        }
+    }
+
+    /* offset ranges painted with a linear color ramp, including the extensions. */
+    fn segments(&self, stops: &[(Real, Rgb)], first: Real, last: Real) -> Vec<Segment> {
+        let mut segments = Vec::new();
+
+        if self.extend.0 {
+            let (offset, color) = stops[0];
+            segments.push(Segment::solid(first, offset, color));
+        }
+        for pair in stops.windows(2) {
+            if pair[1].0 > pair[0].0 {
+                segments.push(Segment {
+                    from: pair[0].0,
+                    to: pair[1].0,
+                    from_color: pair[0].1,
+                    to_color: pair[1].1,
+                });
+            }
+        }
+        if self.extend.1 {
+            let (offset, color) = stops[stops.len() - 1];
+            segments.push(Segment::solid(offset, last, color));
+        }
+
+        segments
+    }
+
+    fn axial_triangles(
+        &self,
+        stops: &[(Real, Rgb)],
+        start: Point,
+        end: Point,
+        bbox: Rect,
+    ) -> Result<Vec<[(Point, Rgb); 3]>> {
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let len2 = dx * dx + dy * dy;
+        if len2 == 0.0 {
+            return Err(ErrorKind::InvalidParameter.into());
+        }
+        let offset = |p: Point| ((p.x - start.x) * dx + (p.y - start.y) * dy) / len2;
+
+        let corners = [
+            Point {
+                x: bbox.left,
+                y: bbox.bottom,
+            },
+            Point {
+                x: bbox.right,
+                y: bbox.bottom,
+            },
+            Point {
+                x: bbox.right,
+                y: bbox.top,
+            },
+            Point {
+                x: bbox.left,
+                y: bbox.top,
+            },
+        ];
+        let first = corners.iter().map(|&p| offset(p)).fold(0.0, Real::min);
+        let last = corners.iter().map(|&p| offset(p)).fold(1.0, Real::max);
+
+        let mut triangles = Vec::new();
+        for segment in self.segments(stops, first, last) {
+            /* the part of bbox between the lines perpendicular to the axis at both offsets. */
+            let polygon = clip_polygon(&corners, |p| offset(p) - segment.from);
+            let polygon = clip_polygon(&polygon, |p| segment.to - offset(p));
+
+            let vertex = |p: Point| (p, segment.color_at(offset(p)));
+            for i in 1..polygon.len().saturating_sub(1) {
+                triangles.push([
+                    vertex(polygon[0]),
+                    vertex(polygon[i]),
+                    vertex(polygon[i + 1]),
+                ]);
+            }
+        }
+
+        Ok(triangles)
+    }
+
+    fn radial_triangles(
+        &self,
+        stops: &[(Real, Rgb)],
+        circles: &RadialCircles,
+        bbox: Rect,
+    ) -> Result<Vec<[(Point, Rgb); 3]>> {
+        if circles.start_radius < 0.0
+            || circles.end_radius < 0.0
+            || (circles.start_radius == 0.0 && circles.end_radius == 0.0)
+        {
+            return Err(ErrorKind::InvalidParameter.into());
+        }
+
+        let first = circles.extend_limit(0.0, -1.0, bbox);
+        let last = circles.extend_limit(1.0, 1.0, bbox);
+
+        let angle = |i: usize| 2.0 * std::f32::consts::PI * i as Real / RADIAL_SEGMENTS as Real;
+
+        let mut triangles = Vec::new();
+        for segment in self.segments(stops, first, last) {
+            for i in 0..RADIAL_SEGMENTS {
+                let (a1, a2) = (angle(i), angle(i + 1));
+
+                let p1 = (circles.point(segment.from, a1), segment.from_color);
+                let p2 = (circles.point(segment.to, a1), segment.to_color);
+                let p3 = (circles.point(segment.to, a2), segment.to_color);
+                let p4 = (circles.point(segment.from, a2), segment.from_color);
+
+                triangles.push([p1, p2, p3]);
+                triangles.push([p1, p3, p4]);
+            }
+        }
+
+        Ok(triangles)
+    }
+}
+
+/* offset range of a gradient and the colors at both ends. */
+struct Segment {
+    from: Real,
+    to: Real,
+    from_color: Rgb,
+    to_color: Rgb,
+}
+
+impl Segment {
+    fn solid(from: Real, to: Real, color: Rgb) -> Self {
+        Self {
+            from,
+            to,
+            from_color: color,
+            to_color: color,
+        }
+    }
+
+    fn color_at(&self, offset: Real) -> Rgb {
+        if self.to <= self.from {
+            return self.from_color;
+        }
+
+        let t = ((offset - self.from) / (self.to - self.from))
+            .max(0.0)
+            .min(1.0);
+        let (r1, g1, b1) = self.from_color;
+        let (r2, g2, b2) = self.to_color;
+        (r1 + (r2 - r1) * t, g1 + (g2 - g1) * t, b1 + (b2 - b1) * t)
+    }
+}
+
+struct RadialCircles {
+    start: Point,
+    start_radius: Real,
+    end: Point,
+    end_radius: Real,
+}
+
+impl RadialCircles {
+    fn center(&self, s: Real) -> Point {
+        Point {
+            x: self.start.x + (self.end.x - self.start.x) * s,
+            y: self.start.y + (self.end.y - self.start.y) * s,
+        }
+    }
+
+    fn radius(&self, s: Real) -> Real {
+        self.start_radius + (self.end_radius - self.start_radius) * s
+    }
+
+    fn point(&self, s: Real, angle: Real) -> Point {
+        let center = self.center(s);
+        let radius = self.radius(s).max(0.0);
+        let (sin, cos) = angle.sin_cos();
+
+        Point {
+            x: center.x + radius * cos,
+            y: center.y + radius * sin,
+        }
+    }
+
+    /* offset the circles must reach from `from` in `direction` (-1.0 or 1.0) to cover bbox. */
+    fn extend_limit(&self, from: Real, direction: Real, bbox: Rect) -> Real {
+        let growth = (self.end_radius - self.start_radius) * direction;
+        if growth < 0.0 {
+            /* shrinking circles end in a point. */
+            return -self.start_radius / (self.end_radius - self.start_radius);
+        }
+
+        let mut step = 1.0;
+        for _ in 0..MAX_EXTEND_STEPS {
+            let s = from + step * direction;
+            let center = self.center(s);
+            let radius = self.radius(s);
+
+            let covered = [
+                (bbox.left, bbox.bottom),
+                (bbox.right, bbox.bottom),
+                (bbox.right, bbox.top),
+                (bbox.left, bbox.top),
+            ]
+            .iter()
+            .all(|&(x, y)| (x - center.x).hypot(y - center.y) <= radius);
+
+            let nearest_x = center.x.max(bbox.left).min(bbox.right);
+            let nearest_y = center.y.max(bbox.bottom).min(bbox.top);
+            let outside = (nearest_x - center.x).hypot(nearest_y - center.y) > radius;
+            let speed = (self.end.x - self.start.x).hypot(self.end.y - self.start.y);
+
+            if covered || (outside && growth <= speed) {
+                return s;
+            }
+            step *= 2.0;
+        }
+
+        from + step * direction
+    }
+}
+
+/* keep the part of a convex polygon where `distance` is not negative. */
+fn clip_polygon<F>(polygon: &[Point], distance: F) -> Vec<Point>
+where
+    F: Fn(Point) -> Real,
+{
+    let mut ret = Vec::with_capacity(polygon.len() + 1);
+
+    for (i, &p) in polygon.iter().enumerate() {
+        let q = polygon[(i + 1) % polygon.len()];
+        let (dp, dq) = (distance(p), distance(q));
+
+        if dp >= 0.0 {
+            ret.push(p);
+        }
+        if (dp >= 0.0) != (dq >= 0.0) {
+            let t = dp / (dp - dq);
+            ret.push(Point {
+                x: p.x + (q.x - p.x) * t,
+                y: p.y + (q.y - p.y) * t,
+            });
+        }
+    }
+
+    ret
+}
+
+/// Shading handle type.
+///
+/// libharu only writes free-form triangle mesh shadings, so gradients are
+/// converted to triangles: exactly for axial gradients, and with the circles
+/// approximated by polygons for radial ones.
+pub struct Shading<'a> {
+    shading: libharu_sys::HPDF_Shading,
+    doc: &'a Document,
+}
+
+impl<'a> Shading<'a> {
+    pub(crate) fn new(doc: &'a Document, shading: libharu_sys::HPDF_Shading) -> Self {
+        Self { shading, doc }
+    }
+
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_Shading {
+        self.shading
+    }
+
+    pub(crate) fn add_triangle(&self, triangle: &[(Point, Rgb); 3]) -> Result<()> {
+        use libharu_sys::HPDF_Shading_FreeFormTriangleMeshEdgeFlag::*;
+
+        /* every vertex starts a new triangle, so none share edges. */
+        for &(p, (r, g, b)) in triangle {
+            let status = unsafe {
+                libharu_sys::HPDF_Shading_AddVertexRGB(
+                    self.handle(),
+                    HPDF_FREE_FORM_TRI_MESH_EDGEFLAG_NO_CONNECTION,
+                    p.x,
+                    p.y,
+                    to_byte(r),
+                    to_byte(g),
+                    to_byte(b),
+                )
+            };
+
+            if status != 0 {
+                return Err(self.doc.status_error("HPDF_Shading_AddVertexRGB", status));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn to_byte(value: Real) -> u8 {
+    (value.max(0.0).min(1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = (1.0, 0.0, 0.0);
+    const GREEN: Rgb = (0.0, 1.0, 0.0);
+    const BLUE: Rgb = (0.0, 0.0, 1.0);
+
+    fn bbox() -> Rect {
+        Rect::from((0.0, 50.0, 100.0, 0.0))
+    }
+
+    fn close(a: Rgb, b: Rgb) -> bool {
+        (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4 && (a.2 - b.2).abs() < 1e-4
+    }
+
+    fn area(triangles: &[[(Point, Rgb); 3]]) -> Real {
+        triangles
+            .iter()
+            .map(|&[(a, _), (b, _), (c, _)]| {
+                ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+            })
+            .sum()
+    }
+
+    fn covered(triangles: &[[(Point, Rgb); 3]], p: Point) -> bool {
+        let side = |a: Point, b: Point| (b.x - a.x) * (p.y - a.y) - (p.x - a.x) * (b.y - a.y);
+
+        triangles.iter().any(|&[(a, _), (b, _), (c, _)]| {
+            let sides = [side(a, b), side(b, c), side(c, a)];
+            sides.iter().all(|&s| s >= -1e-3) || sides.iter().all(|&s| s <= 1e-3)
+        })
+    }
+
+    fn grid() -> Vec<Point> {
+        let mut points = Vec::new();
+        for i in 0..=20 {
+            for j in 0..=10 {
+                points.push(Point::from((0.5 + 4.95 * i as Real, 0.5 + 4.9 * j as Real)));
+            }
+        }
+
+        points
+    }
+
+    fn three_stops(gradient: Gradient) -> Gradient {
+        gradient
+            .add_stop(1.0, Color::from(BLUE))
+            .add_stop(0.0, Color::from(RED))
+            .add_stop(0.5, Color::from(GREEN))
+    }
+
+    #[test]
+    fn axial_vertex_colors_at_stops() {
+        let gradient = three_stops(Gradient::axial((0.0, 0.0), (100.0, 0.0)));
+        let triangles = gradient.triangles(bbox()).unwrap();
+
+        let mut seen = [false; 3];
+        for &(p, color) in triangles.iter().flatten() {
+            let expected = [(0.0, RED, 0), (50.0, GREEN, 1), (100.0, BLUE, 2)];
+            for &(x, stop, i) in &expected {
+                if (p.x - x).abs() < 1e-3 {
+                    assert!(close(color, stop), "{:?} at {:?}", color, p);
+                    seen[i] = true;
+                }
+            }
+        }
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn axial_extend() {
+        let gradient = three_stops(Gradient::axial((25.0, 0.0), (75.0, 0.0)));
+
+        let triangles = gradient.triangles(bbox()).unwrap();
+        assert!((area(&triangles) - 2500.0).abs() < 1e-2);
+        assert!(triangles
+            .iter()
+            .flatten()
+            .all(|(p, _)| p.x >= 25.0 - 1e-3 && p.x <= 75.0 + 1e-3));
+        assert!(!covered(&triangles, Point::from((10.0, 25.0))));
+        assert!(!covered(&triangles, Point::from((90.0, 25.0))));
+
+        let triangles = gradient.extend(true, true).triangles(bbox()).unwrap();
+        assert!((area(&triangles) - 5000.0).abs() < 1e-2);
+        for &(p, color) in triangles.iter().flatten() {
+            if p.x < 25.0 - 1e-3 {
+                assert!(close(color, RED));
+            }
+            if p.x > 75.0 + 1e-3 {
+                assert!(close(color, BLUE));
+            }
+        }
+    }
+
+    #[test]
+    fn axial_triangles_cover_bbox() {
+        let gradient = three_stops(Gradient::axial((10.0, 40.0), (60.0, 0.0))).extend(true, true);
+        let triangles = gradient.triangles(bbox()).unwrap();
+
+        assert!((area(&triangles) - 5000.0).abs() < 1e-1);
+        assert!(triangles.iter().flatten().all(|(p, _)| p.x >= -1e-3
+            && p.x <= 100.0 + 1e-3
+            && p.y >= -1e-3
+            && p.y <= 50.0 + 1e-3));
+        assert!(grid().into_iter().all(|p| covered(&triangles, p)));
+    }
+
+    #[test]
+    fn radial_extend() {
+        let gradient = three_stops(Gradient::radial((50.0, 25.0), 10.0, (50.0, 25.0), 20.0));
+
+        let triangles = gradient.triangles(bbox()).unwrap();
+        assert!(!covered(&triangles, Point::from((50.0, 25.0))));
+        assert!(covered(&triangles, Point::from((65.0, 25.0))));
+        assert!(!covered(&triangles, Point::from((5.0, 5.0))));
+        for &(p, color) in triangles.iter().flatten() {
+            let distance = (p.x - 50.0).hypot(p.y - 25.0);
+            if (distance - 10.0).abs() < 1e-3 {
+                assert!(close(color, RED));
+            }
+            if (distance - 20.0).abs() < 1e-3 {
+                assert!(close(color, BLUE));
+            }
+        }
+
+        let triangles = gradient.extend(true, true).triangles(bbox()).unwrap();
+        assert!(covered(&triangles, Point::from((50.0, 25.0))));
+        assert!(grid().into_iter().all(|p| covered(&triangles, p)));
+    }
+
+    #[test]
+    fn empty_gradient_is_rejected() {
+        let err = Gradient::axial((0.0, 0.0), (1.0, 0.0))
+            .triangles(bbox())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidParameter);
+
+        let err = Gradient::axial((1.0, 1.0), (1.0, 1.0))
+            .add_stop(0.0, ShadingColor::Gray(0.5))
+            .triangles(bbox())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidParameter);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::document::Document;
+
+use std::io::{self, Read};
+
+/// Reader over the document saved to libharu's memory stream.
+///
+/// Created by `Document::reader`. The stream is rewound when the reader is dropped.
+pub struct DocumentReader<'a> {
+    doc: &'a Document,
+    remaining: u32,
+}
+
+impl<'a> DocumentReader<'a> {
+    pub(crate) fn new(doc: &'a Document, size: u32) -> Self {
+        Self {
+            doc,
+            remaining: size,
+        }
+    }
+
+    /// Get the number of bytes left to read.
+    pub fn len(&self) -> usize {
+        self.remaining as usize
+    }
+
+    /// Check whether the whole stream has been read.
+    pub fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl<'a> Read for DocumentReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining == 0 || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let len = buf.len().min(self.remaining as usize);
+        let read = self
+            .doc
+            .read_from_stream(&mut buf[..len])
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        if read == 0 {
+            self.remaining = 0;
+        } else {
+            self.remaining -= read as u32;
+        }
+
+        Ok(read)
+    }
+}
+
+impl<'a> Drop for DocumentReader<'a> {
+    fn drop(&mut self) {
+        let _ = self.doc.reset_stream();
+    }
+}
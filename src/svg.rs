@@ -0,0 +1,649 @@
+use crate::context::{PageDescTeextCommonFunction, PageDescriptionMode};
+use crate::error::ErrorKind;
+use crate::ext_gstate::ExtGState;
+use crate::page::{FillRule, Page};
+use crate::svg_path;
+use crate::{Color, Font, Matrix, Real, Rect, Result};
+
+/// Part of an SVG document which could not be drawn and was skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SvgWarning {
+    /// The element is not supported, and was skipped with its children.
+    UnsupportedElement(String),
+
+    /// The value of an attribute is invalid or not supported, and was ignored.
+    InvalidAttribute {
+        /// Name of the element
+        element: String,
+
+        /// Name of the attribute
+        attribute: String,
+
+        /// Value of the attribute
+        value: String,
+    },
+
+    /// A `text` element was skipped because no font was given.
+    MissingFont,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/* inherited presentation properties. */
+#[derive(Debug, Clone)]
+struct Style {
+    fill: Option<Color>,
+    stroke: Option<Color>,
+    stroke_width: Real,
+    fill_rule: FillRule,
+    opacity: Real,
+    fill_opacity: Real,
+    stroke_opacity: Real,
+    font_size: Real,
+    text_anchor: TextAnchor,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: Some(Color {
+                red: 0.0,
+                green: 0.0,
+                blue: 0.0,
+            }),
+            stroke: None,
+            stroke_width: 1.0,
+            fill_rule: FillRule::NonZero,
+            opacity: 1.0,
+            fill_opacity: 1.0,
+            stroke_opacity: 1.0,
+            font_size: 16.0,
+            text_anchor: TextAnchor::Start,
+        }
+    }
+}
+
+/// Draw an SVG document scaled into `rect`, as `Page::draw_svg`.
+pub(crate) fn draw(
+    page: &Page,
+    source: &str,
+    rect: Rect,
+    font: Option<&Font>,
+) -> Result<Vec<SvgWarning>> {
+    let doc = roxmltree::Document::parse(source).map_err(|_| ErrorKind::InvalidSvg)?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "svg" {
+        return Err(ErrorKind::InvalidSvg.into());
+    }
+
+    let page = PageDescriptionMode::new(page);
+    let mut renderer = Renderer {
+        page: &page,
+        font,
+        gstates: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let viewport = renderer.viewport(root, rect);
+
+    /* like the SVG viewport, nothing is drawn outside of rect. */
+    page.with_saved_state(|page| {
+        page.run_path_mode(|path| {
+            path.rectangle(
+                (rect.left, rect.bottom),
+                rect.right - rect.left,
+                rect.top - rect.bottom,
+            )?;
+            path.clip(FillRule::NonZero)?;
+            path.end_path()
+        })?;
+
+        renderer.element(root, viewport, &Style::default())
+    })?;
+
+    Ok(renderer.warnings)
+}
+
+struct Renderer<'a, 'doc, 'page> {
+    page: &'a PageDescriptionMode<'doc, 'page>,
+    font: Option<&'a Font<'a>>,
+
+    /* graphics states created for each (fill, stroke) alpha pair, in 1/1000. */
+    gstates: Vec<((u32, u32), ExtGState<'doc>)>,
+    warnings: Vec<SvgWarning>,
+}
+
+impl<'a, 'doc, 'page> Renderer<'a, 'doc, 'page> {
+    /* map the viewBox into rect, centered and keeping the aspect ratio unless told not to. */
+    fn viewport(&mut self, root: roxmltree::Node, rect: Rect) -> Matrix {
+        let (width, height) = (rect.right - rect.left, rect.top - rect.bottom);
+
+        let view_box = root
+            .attribute("viewBox")
+            .and_then(|value| match svg_path::numbers(value) {
+                Ok(ref v) if v.len() == 4 && v[2] > 0.0 && v[3] > 0.0 => {
+                    Some((v[0], v[1], v[2], v[3]))
+                }
+                _ => {
+                    self.invalid_attribute(root, "viewBox", value);
+                    None
+                }
+            });
+        let (min_x, min_y, view_width, view_height) = match view_box {
+            Some(view_box) => view_box,
+            None => (
+                0.0,
+                0.0,
+                self.length(root, "width").unwrap_or(width),
+                self.length(root, "height").unwrap_or(height),
+            ),
+        };
+
+        let (mut sx, mut sy) = (width / view_width, height / view_height);
+        match root.attribute("preserveAspectRatio").map(str::trim) {
+            Some("none") => {}
+            Some(value) if value != "xMidYMid" && value != "xMidYMid meet" => {
+                self.invalid_attribute(root, "preserveAspectRatio", value);
+                sx = sx.min(sy);
+                sy = sx;
+            }
+            _ => {
+                sx = sx.min(sy);
+                sy = sx;
+            }
+        }
+        let offset_x = (width - view_width * sx) / 2.0;
+        let offset_y = (height - view_height * sy) / 2.0;
+
+        Matrix::translate(-min_x, -min_y)
+            .multiply(&Matrix::scale(sx, -sy))
+            .multiply(&Matrix::translate(
+                rect.left + offset_x,
+                rect.top - offset_y,
+            ))
+    }
+
+    fn element(&mut self, node: roxmltree::Node, ctm: Matrix, parent: &Style) -> Result<()> {
+        let name = node.tag_name().name();
+
+        if node.attribute("display") == Some("none") {
+            return Ok(());
+        }
+
+        let mut style = parent.clone();
+        self.apply_style(node, &mut style);
+
+        let ctm = match node.attribute("transform") {
+            Some(value) => match parse_transform(value) {
+                Some(transform) => transform.multiply(&ctm),
+                None => {
+                    self.invalid_attribute(node, "transform", value);
+                    ctm
+                }
+            },
+            None => ctm,
+        };
+
+        match name {
+            "svg" | "g" => {
+                for child in node.children().filter(|n| n.is_element()) {
+                    self.element(child, ctm, &style)?;
+                }
+                Ok(())
+            }
+            "path" => {
+                let d = node.attribute("d").unwrap_or("");
+                self.shape(node, ctm, &style, d)
+            }
+            "rect" => {
+                let x = self.length(node, "x").unwrap_or(0.0);
+                let y = self.length(node, "y").unwrap_or(0.0);
+                let w = self.length(node, "width").unwrap_or(0.0);
+                let h = self.length(node, "height").unwrap_or(0.0);
+                if w <= 0.0 || h <= 0.0 {
+                    return Ok(());
+                }
+
+                let rx = self.length(node, "rx");
+                let ry = self.length(node, "ry");
+                let d = match (rx.or(ry), ry.or(rx)) {
+                    (Some(rx), Some(ry)) if rx > 0.0 && ry > 0.0 => {
+                        let (rx, ry) = (rx.min(w / 2.0), ry.min(h / 2.0));
+                        let mut d = format!("M{} {} H{} ", x + rx, y, x + w - rx);
+                        d.push_str(&format!("A{} {} 0 0 1 {} {} ", rx, ry, x + w, y + ry));
+                        d.push_str(&format!("V{} ", y + h - ry));
+                        d.push_str(&format!("A{} {} 0 0 1 {} {} ", rx, ry, x + w - rx, y + h));
+                        d.push_str(&format!("H{} ", x + rx));
+                        d.push_str(&format!("A{} {} 0 0 1 {} {} ", rx, ry, x, y + h - ry));
+                        d.push_str(&format!("V{} ", y + ry));
+                        d.push_str(&format!("A{} {} 0 0 1 {} {} Z", rx, ry, x + rx, y));
+                        d
+                    }
+                    _ => format!("M{} {} h{} v{} h{} Z", x, y, w, h, -w),
+                };
+                self.shape(node, ctm, &style, &d)
+            }
+            "circle" | "ellipse" => {
+                let cx = self.length(node, "cx").unwrap_or(0.0);
+                let cy = self.length(node, "cy").unwrap_or(0.0);
+                let (rx, ry) = if name == "circle" {
+                    let r = self.length(node, "r").unwrap_or(0.0);
+                    (r, r)
+                } else {
+                    (
+                        self.length(node, "rx").unwrap_or(0.0),
+                        self.length(node, "ry").unwrap_or(0.0),
+                    )
+                };
+                if rx <= 0.0 || ry <= 0.0 {
+                    return Ok(());
+                }
+
+                let d = format!(
+                    "M{} {} A{} {} 0 1 0 {} {} A{} {} 0 1 0 {} {} Z",
+                    cx - rx,
+                    cy,
+                    rx,
+                    ry,
+                    cx + rx,
+                    cy,
+                    rx,
+                    ry,
+                    cx - rx,
+                    cy
+                );
+                self.shape(node, ctm, &style, &d)
+            }
+            "line" => {
+                let d = format!(
+                    "M{} {} L{} {}",
+                    self.length(node, "x1").unwrap_or(0.0),
+                    self.length(node, "y1").unwrap_or(0.0),
+                    self.length(node, "x2").unwrap_or(0.0),
+                    self.length(node, "y2").unwrap_or(0.0)
+                );
+                style.fill = None;
+                self.shape(node, ctm, &style, &d)
+            }
+            "polyline" | "polygon" => {
+                let value = node.attribute("points").unwrap_or("");
+                let points = match svg_path::numbers(value) {
+                    Ok(points) => points,
+                    Err(_) => {
+                        self.invalid_attribute(node, "points", value);
+                        return Ok(());
+                    }
+                };
+                if points.len() < 4 {
+                    return Ok(());
+                }
+
+                let mut d = String::new();
+                for (i, point) in points.chunks_exact(2).enumerate() {
+                    let command = if i == 0 { 'M' } else { 'L' };
+                    d.push_str(&format!("{}{} {} ", command, point[0], point[1]));
+                }
+                if name == "polygon" {
+                    d.push('Z');
+                }
+                self.shape(node, ctm, &style, &d)
+            }
+            "text" => self.text(node, ctm, &style),
+            "title" | "desc" | "metadata" => Ok(()),
+            _ => {
+                self.warnings
+                    .push(SvgWarning::UnsupportedElement(name.to_string()));
+                Ok(())
+            }
+        }
+    }
+
+    fn apply_style(&mut self, node: roxmltree::Node, style: &mut Style) {
+        /* opacity multiplies the inherited one, so only its last valid value is applied. */
+        let mut opacity = 1.0;
+        let mut properties: Vec<(&str, &str)> = node
+            .attributes()
+            .filter(|a| a.namespace().is_none())
+            .map(|a| (a.name(), a.value()))
+            .collect();
+
+        /* the style attribute takes precedence over presentation attributes. */
+        if let Some(value) = node.attribute("style") {
+            properties.extend(value.split(';').filter_map(|declaration| {
+                let mut parts = declaration.splitn(2, ':');
+                match (parts.next(), parts.next()) {
+                    (Some(name), Some(value)) => Some((name.trim(), value.trim())),
+                    _ => None,
+                }
+            }));
+        }
+
+        for (name, value) in properties {
+            let value = value.trim();
+            let valid = match name {
+                "fill" => parse_paint(value).map(|color| style.fill = color).is_some(),
+                "stroke" => parse_paint(value)
+                    .map(|color| style.stroke = color)
+                    .is_some(),
+                "stroke-width" => parse_length(value)
+                    .map(|width| style.stroke_width = width)
+                    .is_some(),
+                "fill-rule" => match value {
+                    "nonzero" => {
+                        style.fill_rule = FillRule::NonZero;
+                        true
+                    }
+                    "evenodd" => {
+                        style.fill_rule = FillRule::EvenOdd;
+                        true
+                    }
+                    _ => false,
+                },
+                "opacity" => parse_opacity(value).map(|v| opacity = v).is_some(),
+                "fill-opacity" => parse_opacity(value)
+                    .map(|v| style.fill_opacity = v)
+                    .is_some(),
+                "stroke-opacity" => parse_opacity(value)
+                    .map(|v| style.stroke_opacity = v)
+                    .is_some(),
+                "font-size" => parse_length(value)
+                    .map(|size| style.font_size = size)
+                    .is_some(),
+                "text-anchor" => match value {
+                    "start" | "middle" | "end" => {
+                        style.text_anchor = match value {
+                            "start" => TextAnchor::Start,
+                            "middle" => TextAnchor::Middle,
+                            _ => TextAnchor::End,
+                        };
+                        true
+                    }
+                    _ => false,
+                },
+                _ => true,
+            };
+
+            if !valid {
+                self.invalid_attribute(node, name, value);
+            }
+        }
+
+        style.opacity *= opacity;
+    }
+
+    fn shape(&mut self, node: roxmltree::Node, ctm: Matrix, style: &Style, d: &str) -> Result<()> {
+        let segments = match svg_path::parse(d) {
+            Ok(ref segments) if segments.is_empty() => return Ok(()),
+            Ok(segments) => segments,
+            Err(_) => {
+                self.invalid_attribute(node, "d", d);
+                return Ok(());
+            }
+        };
+        if style.fill.is_none() && style.stroke.is_none() {
+            return Ok(());
+        }
+
+        let gstate = self.gstate(style)?;
+        let gstate = gstate.map(|i| &self.gstates[i].1);
+
+        self.page.with_transform(ctm, |page| {
+            if let Some(gstate) = gstate {
+                page.set_ext_gstate(gstate)?;
+            }
+            if let Some(color) = style.fill {
+                page.set_rgb_fill(color)?;
+            }
+            if let Some(color) = style.stroke {
+                page.set_rgb_stroke(color)?;
+                page.set_line_width(style.stroke_width)?;
+            }
+
+            page.run_path_mode(|path| {
+                path.segments(&segments, Matrix::identity())?;
+                match (style.fill.is_some(), style.stroke.is_some()) {
+                    (true, true) => path.fill_stroke(style.fill_rule),
+                    (true, false) => path.fill(style.fill_rule),
+                    _ => path.stroke(),
+                }
+            })
+        })
+    }
+
+    fn text(&mut self, node: roxmltree::Node, ctm: Matrix, style: &Style) -> Result<()> {
+        let font = match self.font {
+            Some(font) => font,
+            None => {
+                self.warnings.push(SvgWarning::MissingFont);
+                return Ok(());
+            }
+        };
+
+        let mut runs = Vec::new();
+        self.text_runs(node, &mut runs);
+        let text = runs.join(" ");
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let color = match style.fill {
+            Some(color) if !text.is_empty() => color,
+            _ => return Ok(()),
+        };
+        let x = self.length(node, "x").unwrap_or(0.0);
+        let y = self.length(node, "y").unwrap_or(0.0);
+
+        let gstate = self.gstate(style)?;
+        let gstate = gstate.map(|i| &self.gstates[i].1);
+
+        self.page.with_transform(ctm, |page| {
+            if let Some(gstate) = gstate {
+                page.set_ext_gstate(gstate)?;
+            }
+            page.set_rgb_fill(color)?;
+
+            page.run_text_mode(|page| {
+                page.set_font_and_size(font, style.font_size)?;
+                let width = page.text_width(&text)?;
+                let x = match style.text_anchor {
+                    TextAnchor::Start => x,
+                    TextAnchor::Middle => x - width / 2.0,
+                    TextAnchor::End => x - width,
+                };
+
+                /* flip the glyphs back upright in the y-down user space. */
+                page.set_text_matrix(1.0, 0.0, 0.0, -1.0, x, y)?;
+                page.show_text(&text)
+            })
+        })
+    }
+
+    /* tspan positions and styles are not supported, only their text is kept. */
+    fn text_runs<'t>(&mut self, node: roxmltree::Node<'t, '_>, runs: &mut Vec<&'t str>) {
+        for child in node.children() {
+            if child.is_text() {
+                runs.extend(child.text());
+            } else if child.has_tag_name("tspan") {
+                self.text_runs(child, runs);
+            } else if child.is_element() {
+                self.warnings.push(SvgWarning::UnsupportedElement(
+                    child.tag_name().name().to_string(),
+                ));
+            }
+        }
+    }
+
+    /* index of the graphics state applying the opacity of the style, if any. */
+    fn gstate(&mut self, style: &Style) -> Result<Option<usize>> {
+        let fill = (style.opacity * style.fill_opacity * 1000.0).round() as u32;
+        let stroke = (style.opacity * style.stroke_opacity * 1000.0).round() as u32;
+        if fill >= 1000 && stroke >= 1000 {
+            return Ok(None);
+        }
+
+        if let Some(i) = self
+            .gstates
+            .iter()
+            .position(|(key, _)| *key == (fill, stroke))
+        {
+            return Ok(Some(i));
+        }
+
+        let gstate = self.page.doc().create_ext_gstate()?;
+        gstate.set_alpha_fill(fill as Real / 1000.0)?;
+        gstate.set_alpha_stroke(stroke as Real / 1000.0)?;
+        self.gstates.push(((fill, stroke), gstate));
+
+        Ok(Some(self.gstates.len() - 1))
+    }
+
+    fn length(&mut self, node: roxmltree::Node, attribute: &str) -> Option<Real> {
+        let value = node.attribute(attribute)?;
+        let ret = parse_length(value);
+        if ret.is_none() {
+            self.invalid_attribute(node, attribute, value);
+        }
+
+        ret
+    }
+
+    fn invalid_attribute(&mut self, node: roxmltree::Node, attribute: &str, value: &str) {
+        self.warnings.push(SvgWarning::InvalidAttribute {
+            element: node.tag_name().name().to_string(),
+            attribute: attribute.to_string(),
+            value: value.to_string(),
+        });
+    }
+}
+
+/* a number in user units, optionally with an absolute unit. (96 user units per inch) */
+fn parse_length(value: &str) -> Option<Real> {
+    const UNITS: [(&str, Real); 6] = [
+        ("px", 1.0),
+        ("pt", 96.0 / 72.0),
+        ("pc", 16.0),
+        ("mm", 96.0 / 25.4),
+        ("cm", 96.0 / 2.54),
+        ("in", 96.0),
+    ];
+
+    let value = value.trim();
+    let (number, scale) = UNITS
+        .iter()
+        .find_map(|&(unit, scale)| value.strip_suffix(unit).map(|number| (number, scale)))
+        .unwrap_or((value, 1.0));
+
+    number.trim().parse::<Real>().ok().map(|v| v * scale)
+}
+
+fn parse_opacity(value: &str) -> Option<Real> {
+    value
+        .trim()
+        .parse::<Real>()
+        .ok()
+        .map(|v| v.max(0.0).min(1.0))
+}
+
+/* `Some(None)` for "none", `None` if the paint is invalid or not a plain color. */
+fn parse_paint(value: &str) -> Option<Option<Color>> {
+    let value = value.trim();
+    let rgb = |r: u8, g: u8, b: u8| {
+        Some(Some(Color {
+            red: r as Real / 255.0,
+            green: g as Real / 255.0,
+            blue: b as Real / 255.0,
+        }))
+    };
+
+    if value == "none" {
+        return Some(None);
+    }
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let digit = |i: usize| u8::from_str_radix(hex.get(i..i + 1)?, 16).ok();
+        return match hex.len() {
+            3 => rgb(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17),
+            6 => rgb(
+                digit(0)? * 16 + digit(1)?,
+                digit(2)? * 16 + digit(3)?,
+                digit(4)? * 16 + digit(5)?,
+            ),
+            _ => None,
+        };
+    }
+
+    if let Some(args) = value.strip_prefix("rgb(").and_then(|v| v.strip_suffix(')')) {
+        let mut channels = [0u8; 3];
+        let mut args = args.split(',');
+        for channel in &mut channels {
+            let arg = args.next()?.trim();
+            *channel = match arg.strip_suffix('%') {
+                Some(percent) => {
+                    (percent.trim().parse::<Real>().ok()?.max(0.0).min(100.0) * 2.55).round() as u8
+                }
+                None => arg.parse::<Real>().ok()?.max(0.0).min(255.0).round() as u8,
+            };
+        }
+        if args.next().is_some() {
+            return None;
+        }
+        return rgb(channels[0], channels[1], channels[2]);
+    }
+
+    match value {
+        "black" => rgb(0, 0, 0),
+        "white" => rgb(255, 255, 255),
+        "red" => rgb(255, 0, 0),
+        "green" => rgb(0, 128, 0),
+        "lime" => rgb(0, 255, 0),
+        "blue" => rgb(0, 0, 255),
+        "navy" => rgb(0, 0, 128),
+        "yellow" => rgb(255, 255, 0),
+        "orange" => rgb(255, 165, 0),
+        "purple" => rgb(128, 0, 128),
+        "gray" | "grey" => rgb(128, 128, 128),
+        "silver" => rgb(192, 192, 192),
+        "maroon" => rgb(128, 0, 0),
+        "olive" => rgb(128, 128, 0),
+        "teal" => rgb(0, 128, 128),
+        "aqua" | "cyan" => rgb(0, 255, 255),
+        "fuchsia" | "magenta" => rgb(255, 0, 255),
+        _ => None,
+    }
+}
+
+/* transform list, `None` if any part is invalid. */
+fn parse_transform(value: &str) -> Option<Matrix> {
+    let mut ret = Matrix::identity();
+    let mut rest = value.trim();
+
+    while !rest.is_empty() {
+        let open = rest.find('(')?;
+        let close = open + rest[open..].find(')')?;
+        let args = svg_path::numbers(&rest[open + 1..close]).ok()?;
+
+        let transform = match (rest[..open].trim(), &args[..]) {
+            ("matrix", &[a, b, c, d, x, y]) => Matrix { a, b, c, d, x, y },
+            ("translate", &[tx]) => Matrix::translate(tx, 0.0),
+            ("translate", &[tx, ty]) => Matrix::translate(tx, ty),
+            ("scale", &[s]) => Matrix::scale(s, s),
+            ("scale", &[sx, sy]) => Matrix::scale(sx, sy),
+            ("rotate", &[angle]) => Matrix::rotate(angle),
+            ("rotate", &[angle, cx, cy]) => Matrix::translate(-cx, -cy)
+                .multiply(&Matrix::rotate(angle))
+                .multiply(&Matrix::translate(cx, cy)),
+            ("skewX", &[angle]) => Matrix::skew(0.0, angle),
+            ("skewY", &[angle]) => Matrix::skew(angle, 0.0),
+            _ => return None,
+        };
+
+        /* the rightmost transform of the list applies first. */
+        ret = transform.multiply(&ret);
+        rest = rest[close + 1..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    Some(ret)
+}
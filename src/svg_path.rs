@@ -0,0 +1,392 @@
+use crate::error::ErrorKind;
+use crate::{Point, Real, Result};
+
+use std::f32::consts::PI;
+
+/// Segment of a parsed SVG path, in absolute SVG coordinates.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Segment {
+    MoveTo(Point),
+    LineTo(Point),
+    CurveTo(Point, Point, Point),
+    ClosePath,
+}
+
+/// Parse SVG path data (the `d` attribute) into move, line and cubic Bézier segments.
+///
+/// Quadratic Béziers and elliptical arcs are converted to cubic Béziers.
+/// On invalid data, the error is `ErrorKind::InvalidSvgPath` with the byte offset.
+pub(crate) fn parse(d: &str) -> Result<Vec<Segment>> {
+    let mut parser = Parser::new(d);
+    parser.run()?;
+
+    Ok(parser.segments)
+}
+
+/// Parse a list of numbers separated by whitespace or commas, as used in SVG attributes.
+#[cfg(feature = "svg")]
+pub(crate) fn numbers(text: &str) -> Result<Vec<Real>> {
+    let mut parser = Parser::new(text);
+    let mut ret = Vec::new();
+
+    loop {
+        parser.skip_separators();
+        if parser.pos == parser.data.len() {
+            break;
+        }
+        ret.push(parser.number()?);
+    }
+
+    Ok(ret)
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    segments: Vec<Segment>,
+
+    /* current point and start of the current subpath. */
+    current: Point,
+    start: Point,
+
+    /* control point reflected by a following S or T command. */
+    cubic_ctrl: Option<Point>,
+    quad_ctrl: Option<Point>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            data: text.as_bytes(),
+            pos: 0,
+            segments: Vec::new(),
+            current: Point::default(),
+            start: Point::default(),
+            cubic_ctrl: None,
+            quad_ctrl: None,
+        }
+    }
+
+    fn run(&mut self) -> Result<()> {
+        let mut command: Option<u8> = None;
+
+        loop {
+            self.skip_separators();
+            let c = match self.data.get(self.pos) {
+                Some(&c) => c,
+                None => break,
+            };
+
+            let cmd = if c.is_ascii_alphabetic() {
+                if !b"MmLlHhVvCcSsQqTtAaZz".contains(&c) {
+                    return Err(self.error());
+                }
+                self.pos += 1;
+                c
+            } else {
+                /* numbers without a command repeat the previous one, moveto as lineto. */
+                match command {
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(cmd) if !matches!(cmd, b'Z' | b'z') => cmd,
+                    _ => return Err(self.error()),
+                }
+            };
+
+            if command.is_none() && !matches!(cmd, b'M' | b'm') {
+                return Err(ErrorKind::InvalidSvgPath(self.pos - 1).into());
+            }
+
+            self.command(cmd)?;
+            command = Some(cmd);
+        }
+
+        Ok(())
+    }
+
+    fn command(&mut self, cmd: u8) -> Result<()> {
+        let relative = cmd.is_ascii_lowercase();
+        let origin = if relative {
+            self.current
+        } else {
+            Point::default()
+        };
+        let point = |x: Real, y: Real| Point {
+            x: origin.x + x,
+            y: origin.y + y,
+        };
+
+        let mut cubic_ctrl = None;
+        let mut quad_ctrl = None;
+
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                let p = point(self.number()?, self.number()?);
+                self.segments.push(Segment::MoveTo(p));
+                self.current = p;
+                self.start = p;
+            }
+            b'L' => {
+                let p = point(self.number()?, self.number()?);
+                self.line_to(p);
+            }
+            b'H' => {
+                let x = self.number()?;
+                let p = Point {
+                    x: origin.x + x,
+                    y: self.current.y,
+                };
+                self.line_to(p);
+            }
+            b'V' => {
+                let y = self.number()?;
+                let p = Point {
+                    x: self.current.x,
+                    y: origin.y + y,
+                };
+                self.line_to(p);
+            }
+            b'C' => {
+                let c1 = point(self.number()?, self.number()?);
+                let c2 = point(self.number()?, self.number()?);
+                let p = point(self.number()?, self.number()?);
+                self.curve_to(c1, c2, p);
+                cubic_ctrl = Some(c2);
+            }
+            b'S' => {
+                let c1 = self.reflect(self.cubic_ctrl);
+                let c2 = point(self.number()?, self.number()?);
+                let p = point(self.number()?, self.number()?);
+                self.curve_to(c1, c2, p);
+                cubic_ctrl = Some(c2);
+            }
+            b'Q' => {
+                let q = point(self.number()?, self.number()?);
+                let p = point(self.number()?, self.number()?);
+                self.quad_to(q, p);
+                quad_ctrl = Some(q);
+            }
+            b'T' => {
+                let q = self.reflect(self.quad_ctrl);
+                let p = point(self.number()?, self.number()?);
+                self.quad_to(q, p);
+                quad_ctrl = Some(q);
+            }
+            b'A' => {
+                let rx = self.number()?;
+                let ry = self.number()?;
+                let rotation = self.number()?;
+                let large_arc = self.flag()?;
+                let sweep = self.flag()?;
+                let p = point(self.number()?, self.number()?);
+                self.arc_to(rx, ry, rotation, large_arc, sweep, p);
+            }
+            _ => {
+                self.segments.push(Segment::ClosePath);
+                self.current = self.start;
+            }
+        }
+
+        self.cubic_ctrl = cubic_ctrl;
+        self.quad_ctrl = quad_ctrl;
+
+        Ok(())
+    }
+
+    fn line_to(&mut self, p: Point) {
+        self.segments.push(Segment::LineTo(p));
+        self.current = p;
+    }
+
+    fn curve_to(&mut self, c1: Point, c2: Point, p: Point) {
+        self.segments.push(Segment::CurveTo(c1, c2, p));
+        self.current = p;
+    }
+
+    fn quad_to(&mut self, q: Point, p: Point) {
+        let p0 = self.current;
+        let c1 = Point {
+            x: p0.x + 2.0 / 3.0 * (q.x - p0.x),
+            y: p0.y + 2.0 / 3.0 * (q.y - p0.y),
+        };
+        let c2 = Point {
+            x: p.x + 2.0 / 3.0 * (q.x - p.x),
+            y: p.y + 2.0 / 3.0 * (q.y - p.y),
+        };
+        self.curve_to(c1, c2, p);
+    }
+
+    /* reflection of the previous control point, or the current point without one. */
+    fn reflect(&self, ctrl: Option<Point>) -> Point {
+        match ctrl {
+            Some(c) => Point {
+                x: 2.0 * self.current.x - c.x,
+                y: 2.0 * self.current.y - c.y,
+            },
+            None => self.current,
+        }
+    }
+
+    /* elliptical arc, following the endpoint to center conversion of SVG 1.1 appendix F.6. */
+    fn arc_to(
+        &mut self,
+        rx: Real,
+        ry: Real,
+        rotation: Real,
+        large_arc: bool,
+        sweep: bool,
+        p: Point,
+    ) {
+        let p1 = self.current;
+        if p1.x == p.x && p1.y == p.y {
+            return;
+        }
+
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(p);
+            return;
+        }
+
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let dx = (p1.x - p.x) / 2.0;
+        let dy = (p1.y - p.y) / 2.0;
+        let x1 = cos * dx + sin * dy;
+        let y1 = -sin * dx + cos * dy;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let num = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let den = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut coef = (num / den).max(0.0).sqrt();
+        if large_arc == sweep {
+            coef = -coef;
+        }
+        let cx1 = coef * rx * y1 / ry;
+        let cy1 = -coef * ry * x1 / rx;
+
+        let cx = cos * cx1 - sin * cy1 + (p1.x + p.x) / 2.0;
+        let cy = sin * cx1 + cos * cy1 + (p1.y + p.y) / 2.0;
+
+        let angle =
+            |ux: Real, uy: Real, vx: Real, vy: Real| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+        let theta = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+        let mut delta = angle(
+            (x1 - cx1) / rx,
+            (y1 - cy1) / ry,
+            (-x1 - cx1) / rx,
+            (-y1 - cy1) / ry,
+        );
+        if !sweep && delta > 0.0 {
+            delta -= 2.0 * PI;
+        } else if sweep && delta < 0.0 {
+            delta += 2.0 * PI;
+        }
+
+        /* split into pieces of at most 90 degrees, each approximated by one cubic Bézier. */
+        let count = (delta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+        let step = delta / count as Real;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+        let map = |ux: Real, uy: Real| Point {
+            x: cx + rx * ux * cos - ry * uy * sin,
+            y: cy + rx * ux * sin + ry * uy * cos,
+        };
+
+        for i in 0..count {
+            let (sin1, cos1) = (theta + step * i as Real).sin_cos();
+            let (sin2, cos2) = (theta + step * (i + 1) as Real).sin_cos();
+
+            let c1 = map(cos1 - k * sin1, sin1 + k * cos1);
+            let c2 = map(cos2 + k * sin2, sin2 - k * cos2);
+            let end = if i + 1 == count { p } else { map(cos2, sin2) };
+            self.curve_to(c1, c2, end);
+        }
+    }
+
+    fn error(&self) -> crate::Error {
+        ErrorKind::InvalidSvgPath(self.pos).into()
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(&c) = self.data.get(self.pos) {
+            if c.is_ascii_whitespace() || c == b',' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Real> {
+        self.skip_separators();
+        let start = self.pos;
+
+        if let Some(b'+') | Some(b'-') = self.data.get(self.pos) {
+            self.pos += 1;
+        }
+        let int_digits = self.digits();
+        let mut frac_digits = 0;
+        if self.data.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            frac_digits = self.digits();
+        }
+        if int_digits + frac_digits == 0 {
+            self.pos = start;
+            return Err(self.error());
+        }
+
+        if let Some(b'e') | Some(b'E') = self.data.get(self.pos) {
+            let mantissa_end = self.pos;
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.data.get(self.pos) {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+
+        /* only ASCII digits, signs, dots and exponents were consumed. */
+        let data = self.data;
+        let text = std::str::from_utf8(&data[start..self.pos]).unwrap_or("");
+        text.parse::<Real>().map_err(|_| {
+            self.pos = start;
+            self.error()
+        })
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(c) = self.data.get(self.pos) {
+            if c.is_ascii_digit() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+
+        self.pos - start
+    }
+
+    /* arc flags are a single digit, which may be followed by the next number without a separator. */
+    fn flag(&mut self) -> Result<bool> {
+        self.skip_separators();
+
+        match self.data.get(self.pos) {
+            Some(b'0') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
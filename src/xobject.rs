@@ -0,0 +1,30 @@
+use crate::document::Document;
+use crate::image::Image;
+
+use std::marker::PhantomData;
+
+/// XObject handle type, drawn with `Page::execute_xobject` or `Page::place_xobject`.
+///
+/// Only images can be used as XObjects: libharu can not write the content
+/// stream of a form XObject. Drawing shared by several pages is written to a
+/// `ContentStream` instead, see `Page::shared_content`.
+pub struct XObject<'a> {
+    xobject: libharu_sys::HPDF_XObject,
+    _doc: PhantomData<&'a Document>,
+}
+
+impl<'a> XObject<'a> {
+    #[inline]
+    pub(crate) fn handle(&self) -> libharu_sys::HPDF_XObject {
+        self.xobject
+    }
+}
+
+impl<'a> From<&Image<'a>> for XObject<'a> {
+    fn from(image: &Image<'a>) -> Self {
+        Self {
+            xobject: image.handle(),
+            _doc: PhantomData,
+        }
+    }
+}
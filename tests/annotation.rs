@@ -0,0 +1,39 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+#[test]
+fn text_markup_covers_every_line() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let quads = [
+        Quad::from(Rect::from((50.0, 712.0, 300.0, 700.0))),
+        Quad::from(Rect::from((50.0, 698.0, 120.5, 686.0))),
+    ];
+    let annots = page
+        .text_markup_annot(TextMarkup::Highlight, &quads, b"reviewed", None)
+        .unwrap();
+    assert_eq!(annots.len(), 2);
+    annots[1].set_color((1.0, 1.0, 0.0)).unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "/QuadPoints"));
+    assert!(contains(&pdf, "120.5"));
+    assert!(contains(&pdf, "(reviewed)"));
+}
+
+#[test]
+fn text_markup_without_quads_is_rejected() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let err = page
+        .text_markup_annot(TextMarkup::Underline, &[], b"", None)
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::InvalidParameter);
+}
@@ -0,0 +1,6 @@
+/// Check whether the PDF bytes contain the text.
+pub fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|w| w == needle.as_bytes())
+}
@@ -0,0 +1,32 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+#[test]
+fn shared_content_is_written_once() {
+    let doc = Document::new(|_| {}).unwrap();
+    let first = doc.add_page().unwrap();
+    let second = doc.add_page().unwrap();
+
+    let letterhead = first
+        .shared_content(|page| {
+            page.run_path_mode(|page| {
+                page.rectangle((12.0, 34.0), 56.0, 78.0)?;
+                page.fill(FillRule::NonZero)
+            })
+        })
+        .unwrap();
+    second.insert_shared_content_stream(&letterhead).unwrap();
+    assert_eq!(first.gmode().unwrap(), GMode::PageDescription);
+
+    let pdf = doc.to_bytes().unwrap();
+    let drawn = pdf
+        .windows("12 34 56 78 re".len())
+        .filter(|w| *w == b"12 34 56 78 re")
+        .count();
+    assert_eq!(drawn, 1);
+    assert!(contains(&pdf, "/Contents ["));
+}
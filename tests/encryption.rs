@@ -0,0 +1,44 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+#[test]
+fn encrypted_document_has_encrypt_dictionary() {
+    let doc = Document::new(|_| {}).unwrap();
+    doc.add_page().unwrap();
+
+    doc.set_password("owner", "user").unwrap();
+    doc.set_permission(Permissions::PRINT | Permissions::COPY)
+        .unwrap();
+    doc.set_encryption_mode(EncryptionMode::Rc4_128, 16)
+        .unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+
+    assert!(contains(&pdf, "/Encrypt"));
+    assert!(contains(&pdf, "/Filter /Standard"));
+    assert!(contains(&pdf, "/R 3"));
+
+    /* PRINT | COPY with the reserved bits libharu always sets (0xFFFFFFC0). */
+    let p = (0xFFFF_FFC0u32 | Permissions::PRINT.bits() | Permissions::COPY.bits()) as i32;
+    assert!(contains(&pdf, &format!("/P {}", p)));
+}
+
+#[test]
+fn encryption_key_length_is_validated() {
+    let doc = Document::new(|_| {}).unwrap();
+    doc.set_password("owner", "user").unwrap();
+
+    let err = doc
+        .set_encryption_mode(EncryptionMode::Rc4_40, 16)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidEncryptKeyLen);
+
+    let err = doc
+        .set_encryption_mode(EncryptionMode::Rc4_128, 17)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidEncryptKeyLen);
+}
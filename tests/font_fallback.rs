@@ -0,0 +1,45 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+// DejaVu Sans cut down to printable ASCII, see tests/fonts/LICENSE.
+const FONT_DATA: &[u8] = include_bytes!("fonts/DejaVuSans-ASCII.ttf");
+
+#[test]
+fn current_font_is_restored() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    let body = doc.font("Times-Roman", None).unwrap();
+    let dejavu = doc.load_ttf_font_from_bytes(FONT_DATA, true, None).unwrap();
+    let mut fallback = FontFallback::new();
+    fallback
+        .add_font(dejavu, Some(CharCoverage::from_ttf(FONT_DATA).unwrap()))
+        .add_font(doc.font("Symbol", None).unwrap(), None);
+
+    page.run_text_mode(|page| {
+        page.set_font_and_size(&body, 14.0)?;
+        page.text_out_with_fallback((50.0, 700.0), &fallback, "a \u{3b1}")?;
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(page.current_font().unwrap().name().unwrap(), "Times-Roman");
+    assert_eq!(page.current_font_size().unwrap(), 14.0);
+}
+
+#[test]
+fn fallback_needs_a_current_font() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    let mut fallback = FontFallback::new();
+    fallback.add_font(doc.font("Helvetica", None).unwrap(), None);
+
+    let err = page
+        .run_text_mode(|page| page.show_text_with_fallback(&fallback, "a"))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::PageFontNotFound);
+}
@@ -0,0 +1,36 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+// DejaVu Sans cut down to printable ASCII, see tests/fonts/LICENSE.
+const FONT_DATA: &[u8] = include_bytes!("fonts/DejaVuSans-ASCII.ttf");
+
+fn render(font_data: &[u8], text: &str) -> Vec<u8> {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    let font = doc.load_ttf_font_from_bytes(font_data, true, None).unwrap();
+    page.run_text_mode(|page| {
+        page.set_font_and_size(&font, 12.0)?;
+        page.text_out((50.0, 700.0), text)?;
+        Ok(())
+    })
+    .unwrap();
+
+    doc.to_bytes().unwrap()
+}
+
+#[test]
+fn embedded_font_is_subset() {
+    let few = render(FONT_DATA, "Hi");
+    let all: String = (0x20u8..0x7f).map(char::from).collect();
+    let many = render(FONT_DATA, &all);
+
+    assert!(contains(&few, "+DejaVuSans"));
+    assert!(few.len() < many.len());
+    assert!(few.len() < FONT_DATA.len() / 2);
+}
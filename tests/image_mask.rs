@@ -0,0 +1,74 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+#[test]
+fn image_introspection() {
+    let doc = Document::new(|_| {}).unwrap();
+
+    let img = doc
+        .load_png_image("examples/pngsuite/basn2c08.png")
+        .unwrap();
+    let size = img.size().unwrap();
+    assert_eq!((size.x, size.y), (32.0, 32.0));
+    assert_eq!(img.bits_per_component().unwrap(), 8);
+    assert_eq!(img.color_space().unwrap(), ColorSpace::DeviceRGB);
+
+    let gray = doc
+        .load_png_image("examples/pngsuite/basn0g08.png")
+        .unwrap();
+    assert_eq!(gray.color_space().unwrap(), ColorSpace::DeviceGray);
+}
+
+#[test]
+fn rgb_image_is_rejected_as_mask() {
+    let doc = Document::new(|_| {}).unwrap();
+
+    let img = doc
+        .load_png_image("examples/pngsuite/basn2c08.png")
+        .unwrap();
+    let mask = doc
+        .load_png_image("examples/pngsuite/maskimage.png")
+        .unwrap();
+
+    let err = img.set_mask_image(&mask).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidColorSpace);
+}
+
+#[test]
+fn color_mask() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    /* maskimage.png is an RGB image, so it is masked by color key. */
+    let mask = doc
+        .load_png_image("examples/pngsuite/maskimage.png")
+        .unwrap();
+    mask.set_color_mask(0, 255, 0, 0, 0, 255).unwrap();
+    page.draw_image(&mask, (100.0, 100.0), 32.0, 32.0).unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "/Mask ["));
+}
+
+#[test]
+fn soft_mask() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let img = doc
+        .load_png_image("examples/pngsuite/basn2c08.png")
+        .unwrap();
+    let mask = doc
+        .load_png_image("examples/pngsuite/basn0g08.png")
+        .unwrap();
+
+    img.set_mask_image(&mask).unwrap();
+    page.draw_image(&img, (100.0, 100.0), 32.0, 32.0).unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "/SMask"));
+}
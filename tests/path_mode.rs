@@ -0,0 +1,21 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+#[test]
+fn unpainted_path_is_ended_and_reported() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    let err = page
+        .run_path_mode(|page| {
+            page.move_to((10.0, 10.0))?;
+            page.line_to((100.0, 100.0))
+        })
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::PageInvalidGMode);
+    assert_eq!(err.function(), None);
+    assert_eq!(page.gmode().unwrap(), GMode::PageDescription);
+}
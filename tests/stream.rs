@@ -0,0 +1,29 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn reading_the_stream_does_not_call_onerror() {
+    let fired = Rc::new(Cell::new(false));
+    let handler = fired.clone();
+    let doc = Document::new(move |_| handler.set(true)).unwrap();
+    doc.add_page().unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(pdf.starts_with(b"%PDF-"));
+
+    let mut written = Vec::new();
+    assert_eq!(doc.write_to(&mut written).unwrap(), pdf.len() as u64);
+
+    /* reading past the end returns 0 instead of an error. */
+    doc.save_to_stream().unwrap();
+    let mut buf = vec![0; pdf.len() + 16];
+    let read = doc.read_from_stream(&mut buf).unwrap();
+    assert_eq!(read, pdf.len());
+    assert_eq!(doc.read_from_stream(&mut buf).unwrap(), 0);
+
+    assert!(!fired.get());
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "svg")]
+
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+const LOGO: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 50">
+  <g transform="translate(10 10)" fill="#ff0000">
+    <rect width="20" height="10"/>
+    <circle cx="50" cy="10" r="5" stroke="blue" stroke-width="2" opacity="0.5"/>
+  </g>
+  <image href="logo.png" width="10" height="10"/>
+  <text x="10" y="40">ACME</text>
+</svg>"##;
+
+#[test]
+fn svg_is_drawn_with_warnings() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let warnings = page.draw_svg(LOGO, (0.0, 100.0, 200.0, 0.0), None).unwrap();
+    assert_eq!(
+        warnings,
+        vec![
+            SvgWarning::UnsupportedElement("image".to_string()),
+            SvgWarning::MissingFont,
+        ]
+    );
+    assert_eq!(page.gstate_depth().unwrap(), 1);
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "1 0 0 rg"));
+    assert!(contains(&pdf, "0 0 1 RG"));
+    assert!(contains(&pdf, "/CA 0.5"));
+}
+
+#[test]
+fn tspan_text_is_kept() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let font = doc.font("Helvetica", None).unwrap();
+
+    let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 50">
+  <text x="10" y="40">Hello <tspan font-weight="bold">big</tspan> <tspan>world</tspan></text>
+</svg>"#;
+    let warnings = page
+        .draw_svg(svg, (0.0, 100.0, 200.0, 0.0), Some(&font))
+        .unwrap();
+    assert!(warnings.is_empty());
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "(Hello big world) Tj"));
+}
+
+#[test]
+fn malformed_svg_is_rejected() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let err = page
+        .draw_svg("<svg><g></svg>", (0.0, 100.0, 100.0, 0.0), None)
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidSvg);
+}
+
+#[test]
+fn style_opacity_overrides_attribute() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100">
+  <rect width="10" height="10" opacity="0.5" style="opacity: 0.8"/>
+</svg>"#;
+    let warnings = page.draw_svg(svg, (0.0, 100.0, 100.0, 0.0), None).unwrap();
+    assert!(warnings.is_empty());
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "/ca 0.8"));
+    assert!(!contains(&pdf, "/ca 0.4"));
+}
+
+#[test]
+fn lengths_with_units() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+
+    let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 200 100">
+  <rect x="1in" width="72pt" height="10mm" stroke-width="1pc"/>
+  <rect x="50%" width="2em" height="10"/>
+</svg>"#;
+    let warnings = page.draw_svg(svg, (0.0, 100.0, 200.0, 0.0), None).unwrap();
+    assert_eq!(
+        warnings,
+        vec![
+            SvgWarning::InvalidAttribute {
+                element: "rect".to_string(),
+                attribute: "x".to_string(),
+                value: "50%".to_string(),
+            },
+            SvgWarning::InvalidAttribute {
+                element: "rect".to_string(),
+                attribute: "width".to_string(),
+                value: "2em".to_string(),
+            },
+        ]
+    );
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "96 0 m"));
+    assert!(contains(&pdf, "192 0 l"));
+}
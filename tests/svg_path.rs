@@ -0,0 +1,62 @@
+extern crate libharu;
+
+use libharu::prelude::*;
+
+mod common;
+use common::contains;
+
+#[test]
+fn svg_path_is_flipped_into_page_space() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    page.run_path_mode(|page| {
+        page.svg_path("M10 10 h20 v20 H10 z", Matrix::flip_y(100.0))?;
+        page.stroke()
+    })
+    .unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "10 90 m"));
+    assert!(contains(&pdf, "30 90 l"));
+    assert!(contains(&pdf, "30 70 l"));
+    assert!(contains(&pdf, "10 70 l"));
+}
+
+#[test]
+fn svg_arcs_become_curves() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    page.run_path_mode(|page| {
+        page.svg_path(
+            "M0,0 A10 10 0 0 1 20 0 Q30,10 40,0 T80,0",
+            Matrix::identity(),
+        )?;
+        page.stroke()
+    })
+    .unwrap();
+
+    let pdf = doc.to_bytes().unwrap();
+    assert!(contains(&pdf, "20 0 c"));
+    assert!(contains(&pdf, "80 0 c"));
+}
+
+#[test]
+fn invalid_svg_path_reports_position() {
+    let doc = Document::new(|_| {}).unwrap();
+    let page = doc.add_page().unwrap();
+    let page = PageDescriptionMode::new(&page);
+
+    let err = page
+        .run_path_mode(|page| page.svg_path("M 0 0 L 10 x", Matrix::identity()))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidSvgPath(11));
+
+    let err = page
+        .run_path_mode(|page| page.svg_path("L 10 10", Matrix::identity()))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidSvgPath(0));
+}